@@ -0,0 +1,411 @@
+//!Camera native RGB, described by DNG style color matrices.
+//!
+//!Raw camera data isn't defined by a set of primaries, but by color matrices
+//!that map CIE XYZ to the camera's native RGB under one or two calibration
+//!illuminants. This module follows the model from the DNG specification: the
+//!matrices are interpolated by the inverse correlated color temperature
+//!(CCT) of the scene white, which is in turn found from the camera neutral or
+//!the white balance multipliers.
+//!
+//!```
+//!# #[macro_use] extern crate approx;
+//!# extern crate palette;
+//!use palette::Xyz;
+//!use palette::camera::{CalibrationIlluminant, CameraProfile, CameraRgb, WhiteBalance};
+//!use palette::white_point::{D50, WhitePoint};
+//!
+//!# fn main() {
+//!let profile = CameraProfile::dual(
+//!    CalibrationIlluminant::new(2856.0, [
+//!        1.1287, -0.4567, -0.0624,
+//!        -0.3795, 1.1647, 0.2382,
+//!        -0.0449, 0.1336, 0.6279,
+//!    ]),
+//!    CalibrationIlluminant::new(6504.0, [
+//!        0.8306, -0.2497, -0.0890,
+//!        -0.4372, 1.2187, 0.2400,
+//!        -0.0885, 0.1866, 0.6457,
+//!    ]),
+//!);
+//!
+//!let white_balance = WhiteBalance::new(2.1, 1.0, 1.4);
+//!let transform = profile.camera_to_xyz::<D50>(&white_balance);
+//!
+//!//A white balanced neutral becomes the D50 white.
+//!let white: Xyz<D50, f64> = transform.convert_balanced(CameraRgb::new(1.0, 1.0, 1.0));
+//!assert_relative_eq!(white, D50::get_xyz(), epsilon = 0.000001);
+//!# }
+//!```
+
+use num_traits::Float;
+
+use std::marker::PhantomData;
+
+use {cast, clamp, Component, IntoColor, Pixel, Xyz, Yxy};
+use chromatic_adaptation::{adaptation_matrix, Method, TransformMatrix};
+use matrix::{matrix_inverse, multiply_3x3, Mat3};
+use white_point::{D50, WhitePoint};
+
+///Raw RGB values in a camera's native color space.
+///
+///The values are neither white balanced nor gamma encoded, and their meaning
+///depends on the [`CameraProfile`](struct.CameraProfile.html) of the camera.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct CameraRgb<T: Component = f32> {
+    ///The response of the red sensor channel.
+    pub red: T,
+
+    ///The response of the green sensor channel.
+    pub green: T,
+
+    ///The response of the blue sensor channel.
+    pub blue: T,
+}
+
+impl<T: Component> CameraRgb<T> {
+    ///Create camera native RGB values.
+    pub fn new(red: T, green: T, blue: T) -> CameraRgb<T> {
+        CameraRgb {
+            red: red,
+            green: green,
+            blue: blue,
+        }
+    }
+}
+
+unsafe impl<T: Component> Pixel<T> for CameraRgb<T> {
+    const CHANNELS: usize = 3;
+}
+
+///A calibration illuminant and its color matrix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CalibrationIlluminant<T: Float> {
+    ///The correlated color temperature of the illuminant, in Kelvin.
+    pub cct: T,
+
+    ///The matrix that converts CIE XYZ to camera native RGB under the
+    ///illuminant. This is what DNG calls `ColorMatrix1` or `ColorMatrix2`.
+    pub color_matrix: Mat3<T>,
+}
+
+impl<T: Float> CalibrationIlluminant<T> {
+    ///Create a calibration illuminant from its CCT and color matrix.
+    pub fn new(cct: T, color_matrix: Mat3<T>) -> CalibrationIlluminant<T> {
+        CalibrationIlluminant {
+            cct: cct,
+            color_matrix: color_matrix,
+        }
+    }
+}
+
+///White balance multipliers for camera native RGB.
+///
+///The multipliers are what is applied to the raw values to make a neutral
+///surface gray. They are the inverse of the camera neutral, which is what DNG
+///calls `AsShotNeutral`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WhiteBalance<T: Component + Float> {
+    multipliers: CameraRgb<T>,
+}
+
+impl<T: Component + Float> WhiteBalance<T> {
+    ///Create white balance from red, green and blue multipliers.
+    pub fn new(red: T, green: T, blue: T) -> WhiteBalance<T> {
+        WhiteBalance {
+            multipliers: CameraRgb::new(red, green, blue),
+        }
+    }
+
+    ///Create white balance from the camera's response to a neutral surface.
+    ///
+    ///The multipliers are normalized to make the green multiplier `1.0`.
+    ///Returns `None` if any of the components isn't a positive, finite
+    ///number, such as for a black or clipped patch, since the multipliers
+    ///would then be infinite or NaN.
+    pub fn from_neutral(neutral: CameraRgb<T>) -> Option<WhiteBalance<T>> {
+        let is_valid = |value: T| value > T::zero() && value.is_finite();
+        if !is_valid(neutral.red) || !is_valid(neutral.green) || !is_valid(neutral.blue) {
+            return None;
+        }
+
+        Some(WhiteBalance::new(
+            neutral.green / neutral.red,
+            T::one(),
+            neutral.green / neutral.blue,
+        ))
+    }
+
+    ///The red, green and blue multipliers.
+    pub fn multipliers(&self) -> CameraRgb<T> {
+        self.multipliers
+    }
+
+    ///The camera's response to a neutral surface, normalized to make the
+    ///largest component `1.0`.
+    pub fn neutral(&self) -> CameraRgb<T> {
+        let red = T::one() / self.multipliers.red;
+        let green = T::one() / self.multipliers.green;
+        let blue = T::one() / self.multipliers.blue;
+        let max = red.max(green).max(blue);
+
+        CameraRgb::new(red / max, green / max, blue / max)
+    }
+
+    ///Apply the multipliers to raw camera values.
+    pub fn apply(&self, raw: CameraRgb<T>) -> CameraRgb<T> {
+        CameraRgb::new(
+            raw.red * self.multipliers.red,
+            raw.green * self.multipliers.green,
+            raw.blue * self.multipliers.blue,
+        )
+    }
+}
+
+///The color calibration of a camera, with one or two calibration illuminants.
+///
+///The color matrices are interpolated linearly by the inverse CCT of the scene
+///white when there are two illuminants, and used as is when there is one.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraProfile<T: Float> {
+    ///The first calibration illuminant.
+    pub illuminant1: CalibrationIlluminant<T>,
+
+    ///The optional second calibration illuminant.
+    pub illuminant2: Option<CalibrationIlluminant<T>>,
+}
+
+impl<T: Component + Float> CameraProfile<T> {
+    ///Create a profile with a single calibration illuminant.
+    pub fn new(illuminant: CalibrationIlluminant<T>) -> CameraProfile<T> {
+        CameraProfile {
+            illuminant1: illuminant,
+            illuminant2: None,
+        }
+    }
+
+    ///Create a profile with two calibration illuminants.
+    pub fn dual(
+        illuminant1: CalibrationIlluminant<T>,
+        illuminant2: CalibrationIlluminant<T>,
+    ) -> CameraProfile<T> {
+        CameraProfile {
+            illuminant1: illuminant1,
+            illuminant2: Some(illuminant2),
+        }
+    }
+
+    ///Get the XYZ to camera matrix for a scene white with the CCT `cct`.
+    ///
+    ///The matrices are weighted by inverse CCT and the weight is clamped, so
+    ///temperatures outside the calibrated range use the nearest matrix.
+    pub fn color_matrix(&self, cct: T) -> Mat3<T> {
+        let illuminant2 = match self.illuminant2 {
+            Some(ref illuminant2) => illuminant2,
+            None => return self.illuminant1.color_matrix,
+        };
+
+        let inv1 = T::one() / self.illuminant1.cct;
+        let inv2 = T::one() / illuminant2.cct;
+        let weight = if inv1 == inv2 {
+            T::one()
+        } else {
+            clamp((T::one() / cct - inv2) / (inv1 - inv2), T::zero(), T::one())
+        };
+
+        let mut matrix = [T::zero(); 9];
+        for (i, m) in matrix.iter_mut().enumerate() {
+            *m = self.illuminant1.color_matrix[i] * weight
+                + illuminant2.color_matrix[i] * (T::one() - weight);
+        }
+
+        matrix
+    }
+
+    ///Find the chromaticity of the scene white from the camera neutral.
+    ///
+    ///The color matrix depends on the CCT of the white, so this is solved
+    ///iteratively, starting from D50. The result is the `(x, y)`
+    ///chromaticity of the white.
+    pub fn neutral_to_xy(&self, neutral: CameraRgb<T>) -> (T, T) {
+        let d50: Yxy<D50, T> = D50::get_xyz::<D50, T>().into_yxy();
+        let (mut x, mut y) = (d50.x, d50.y);
+
+        for _ in 0..30 {
+            let matrix = matrix_inverse(&self.color_matrix(xy_to_cct(x, y)));
+            let xyz: Xyz<D50, T> = multiply_camera(&matrix, &neutral);
+            let sum = xyz.x + xyz.y + xyz.z;
+            let (next_x, next_y) = (xyz.x / sum, xyz.y / sum);
+
+            let converged =
+                (next_x - x).abs() < cast(1e-7) && (next_y - y).abs() < cast(1e-7);
+            x = next_x;
+            y = next_y;
+
+            if converged {
+                break;
+            }
+        }
+
+        (x, y)
+    }
+
+    ///Create a transform from raw camera values to XYZ under the white point
+    ///`Wp`, for a scene with the given white balance.
+    ///
+    ///The camera neutral is mapped to the scene white, which is then adapted
+    ///to `Wp` using the Bradford method.
+    pub fn camera_to_xyz<Wp: WhitePoint>(
+        &self,
+        white_balance: &WhiteBalance<T>,
+    ) -> CameraTransform<Wp, T> {
+        let neutral = white_balance.neutral();
+        let (x, y) = self.neutral_to_xy(neutral);
+        let mut matrix = matrix_inverse(&self.color_matrix(xy_to_cct(x, y)));
+
+        //Scale the matrix to make the neutral map to the luminance 1.0.
+        let neutral_xyz: Xyz<D50, T> = multiply_camera(&matrix, &neutral);
+        let scale = T::one() / neutral_xyz.y;
+        for m in &mut matrix {
+            *m = *m * scale;
+        }
+
+        let scene_white: Xyz<Wp, T> = Yxy::with_wp(x, y, T::one()).into_xyz();
+        let cone_response = TransformMatrix::<Wp, Wp, T>::get_cone_response(&Method::Bradford);
+        let adapt = adaptation_matrix(&cone_response, &scene_white, &Wp::get_xyz::<Wp, T>());
+
+        CameraTransform {
+            matrix: multiply_3x3(&adapt, &matrix),
+            white_balance: *white_balance,
+            white_point: PhantomData,
+        }
+    }
+}
+
+///A precomputed transform from camera native RGB to CIE XYZ.
+///
+///It's created by [`CameraProfile::camera_to_xyz`](struct.CameraProfile.html#method.camera_to_xyz).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CameraTransform<Wp: WhitePoint, T: Component + Float> {
+    matrix: Mat3<T>,
+    white_balance: WhiteBalance<T>,
+    white_point: PhantomData<Wp>,
+}
+
+impl<Wp: WhitePoint, T: Component + Float> CameraTransform<Wp, T> {
+    ///The matrix from raw camera values to XYZ.
+    pub fn matrix(&self) -> Mat3<T> {
+        self.matrix
+    }
+
+    ///The white balance the transform was created for.
+    pub fn white_balance(&self) -> WhiteBalance<T> {
+        self.white_balance
+    }
+
+    ///Convert raw camera values to XYZ.
+    pub fn convert(&self, raw: CameraRgb<T>) -> Xyz<Wp, T> {
+        multiply_camera(&self.matrix, &raw)
+    }
+
+    ///Convert white balanced camera values to XYZ, where `(1.0, 1.0, 1.0)`
+    ///is the white point.
+    pub fn convert_balanced(&self, balanced: CameraRgb<T>) -> Xyz<Wp, T> {
+        let neutral = self.white_balance.neutral();
+        self.convert(CameraRgb::new(
+            balanced.red * neutral.red,
+            balanced.green * neutral.green,
+            balanced.blue * neutral.blue,
+        ))
+    }
+}
+
+fn multiply_camera<Wp: WhitePoint, T: Component + Float>(
+    c: &Mat3<T>,
+    f: &CameraRgb<T>,
+) -> Xyz<Wp, T> {
+    Xyz::with_wp(
+        (c[0] * f.red) + (c[1] * f.green) + (c[2] * f.blue),
+        (c[3] * f.red) + (c[4] * f.green) + (c[5] * f.blue),
+        (c[6] * f.red) + (c[7] * f.green) + (c[8] * f.blue),
+    )
+}
+
+///Approximate the CCT of a chromaticity, using McCamy's formula.
+fn xy_to_cct<T: Float>(x: T, y: T) -> T {
+    let n = (x - cast(0.3320)) / (cast::<T, _>(0.1858) - y);
+    cast::<T, _>(449.0) * n.powi(3) + cast::<T, _>(3525.0) * n.powi(2)
+        + cast::<T, _>(6823.3) * n + cast(5520.33)
+}
+
+#[cfg(test)]
+mod test {
+    use Xyz;
+    use encoding::Srgb;
+    use matrix::{matrix_inverse, rgb_to_xyz_matrix};
+    use white_point::{D65, WhitePoint};
+    use super::{xy_to_cct, CalibrationIlluminant, CameraProfile, CameraRgb, WhiteBalance};
+
+    #[test]
+    fn mccamy_d65() {
+        assert_relative_eq!(xy_to_cct(0.31271, 0.32902), 6504.0, epsilon = 5.0);
+    }
+
+    #[test]
+    fn interpolate_by_inverse_cct() {
+        let profile = CameraProfile::dual(
+            CalibrationIlluminant::new(2856.0, [1.0; 9]),
+            CalibrationIlluminant::new(6504.0, [2.0; 9]),
+        );
+
+        assert_eq!(profile.color_matrix(2856.0), [1.0; 9]);
+        assert_eq!(profile.color_matrix(6504.0), [2.0; 9]);
+        assert_eq!(profile.color_matrix(2000.0), [1.0; 9]);
+        assert_eq!(profile.color_matrix(10000.0), [2.0; 9]);
+
+        let mid = 2.0 / (1.0 / 2856.0 + 1.0 / 6504.0);
+        for m in profile.color_matrix(mid).iter() {
+            assert_relative_eq!(*m, 1.5, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn white_balance_neutral() {
+        let neutral = CameraRgb::new(0.5, 1.0, 0.8);
+        let white_balance = WhiteBalance::from_neutral(neutral).unwrap();
+
+        assert_eq!(white_balance.multipliers(), CameraRgb::new(2.0, 1.0, 1.25));
+        assert_eq!(white_balance.neutral(), neutral);
+        assert_eq!(white_balance.apply(neutral), CameraRgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn white_balance_invalid_neutral() {
+        assert_eq!(WhiteBalance::from_neutral(CameraRgb::new(0.0, 0.0, 0.0)), None);
+        assert_eq!(WhiteBalance::from_neutral(CameraRgb::new(0.5, 1.0, 0.0)), None);
+        assert_eq!(WhiteBalance::from_neutral(CameraRgb::new(-0.1, 1.0, 0.8)), None);
+        let nan = CameraRgb::new(::std::f64::NAN, 1.0, 0.8);
+        assert_eq!(WhiteBalance::from_neutral(nan), None);
+    }
+
+    #[test]
+    fn linear_srgb_camera() {
+        let xyz_to_srgb = matrix_inverse(&rgb_to_xyz_matrix::<Srgb, f64>());
+        let profile = CameraProfile::new(CalibrationIlluminant::new(6504.0, xyz_to_srgb));
+
+        let (x, y) = profile.neutral_to_xy(CameraRgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(x, 0.3127, epsilon = 0.0001);
+        assert_relative_eq!(y, 0.3290, epsilon = 0.0001);
+
+        let transform = profile.camera_to_xyz::<D65>(&WhiteBalance::new(1.0, 1.0, 1.0));
+        for (e, c) in rgb_to_xyz_matrix::<Srgb, f64>()
+            .iter()
+            .zip(transform.matrix().iter())
+        {
+            assert_relative_eq!(e, c, epsilon = 0.0001);
+        }
+
+        let white: Xyz<D65, f64> = transform.convert(CameraRgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, D65::get_xyz(), epsilon = 0.000001);
+    }
+}
@@ -0,0 +1,479 @@
+//!Correlated color temperature (CCT).
+//!
+//!The correlated color temperature of a light source is the temperature of the
+//!black body radiator with the most similar color. The distance from the
+//!Planckian locus in the CIE 1960 UCS diagram is given as `Duv`, which is
+//!positive above the locus (towards green) and negative below it (towards
+//!magenta).
+//!
+//!```
+//!use palette::{Xyz, Yxy};
+//!use palette::cct::{self, Method};
+//!use palette::white_point::D65;
+//!
+//!//A warm white LED.
+//!let white: Yxy<D65, f64> = cct::planckian_with_duv(3000.0, 0.002);
+//!let xyz: Xyz<D65, f64> = white.into();
+//!
+//!let estimate = cct::estimate(xyz, Method::Ohno).unwrap();
+//!assert!((estimate.cct - 3000.0).abs() < 1.0);
+//!assert!((estimate.duv - 0.002).abs() < 0.00001);
+//!```
+
+use num_traits::Float;
+
+use {cast, clamp, Component, IntoColor, Yxy};
+use cie_data::{self, CIE_1931_2, CMF_INTERVAL, CMF_START};
+use white_point::WhitePoint;
+
+///Methods for estimating the correlated color temperature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Method {
+    ///Robertson's method, which interpolates between tabulated isotemperature
+    ///lines. It's fast and covers temperatures from about 1667 K and up.
+    Robertson,
+    ///Ohno's method from "Practical Use and Calculation of CCT and Duv"
+    ///(2014). The closest point in a table of the Planckian locus with 1%
+    ///steps is refined with cascaded, finer tables, and the temperature is
+    ///found with a triangular solution close to the locus and a parabolic
+    ///solution further away from it. It covers temperatures from 1000 K to
+    ///100000 K.
+    Ohno,
+    ///A direct search of the Planckian locus for the closest point, on
+    ///logarithmically spaced temperatures that are narrowed down step by step,
+    ///and refined with a parabolic fit. It's slower, but more accurate, and
+    ///covers temperatures from 1000 K to 100000 K.
+    LocusSearch,
+}
+
+///A correlated color temperature and the distance from the Planckian locus.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Temperature<T> {
+    ///The correlated color temperature, in K.
+    pub cct: T,
+    ///The signed distance from the Planckian locus in the CIE 1960 UCS
+    ///diagram.
+    pub duv: T,
+}
+
+///The chromaticity of a black body radiator at the temperature `cct`, in K,
+///with the luminance `1.0`.
+///
+///The chromaticity is computed from the CIE 1931 2° observer.
+pub fn planckian<Wp, T>(cct: T) -> Yxy<Wp, T>
+where
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let (u, v) = planckian_uv(cct);
+    uv_to_yxy(u, v)
+}
+
+///The chromaticity at the distance `duv` from the Planckian locus at the
+///temperature `cct`, in K, with the luminance `1.0`.
+///
+///The offset is perpendicular to the locus in the CIE 1960 UCS diagram, so
+///`cct` is also the correlated color temperature of the result.
+pub fn planckian_with_duv<Wp, T>(cct: T, duv: T) -> Yxy<Wp, T>
+where
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let (u, v) = planckian_uv(cct);
+
+    let step: T = cast(0.0001);
+    let (u1, v1) = planckian_uv(cct * (T::one() - step));
+    let (u2, v2) = planckian_uv(cct * (T::one() + step));
+    let (du, dv) = (u2 - u1, v2 - v1);
+    let length = (du * du + dv * dv).sqrt();
+
+    //The normal that points towards higher v.
+    let (nu, nv) = if du < T::zero() {
+        (dv / length, -du / length)
+    } else {
+        (-dv / length, du / length)
+    };
+
+    uv_to_yxy(u + nu * duv, v + nv * duv)
+}
+
+///The chromaticity of CIE daylight at the correlated color temperature `cct`,
+///in K, with the luminance `1.0`.
+///
+///The daylight locus is defined from 4000 K to 25000 K. Note that the
+///standard illuminants, such as D65, are defined with the older value of the
+///second radiation constant, so D65 is at 6504 K instead of 6500 K.
+pub fn daylight<Wp, T>(cct: T) -> Yxy<Wp, T>
+where
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let (x, y) = cie_data::daylight_chromaticity(cct);
+    Yxy::with_wp(x, y, T::one())
+}
+
+///Estimate the correlated color temperature and `Duv` of a color, using
+///`method`.
+///
+///Returns `None` if the color is black or if its temperature is outside of
+///the range of the method.
+pub fn estimate<C, Wp, T>(color: C, method: Method) -> Option<Temperature<T>>
+where
+    C: IntoColor<Wp, T>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let xyz = color.into_xyz();
+    let denominator = xyz.x + xyz.y * cast(15.0) + xyz.z * cast(3.0);
+    if denominator <= T::zero() {
+        return None;
+    }
+
+    let u = xyz.x * cast(4.0) / denominator;
+    let v = xyz.y * cast(6.0) / denominator;
+
+    match method {
+        Method::Robertson => robertson(u, v),
+        Method::Ohno => ohno(u, v),
+        Method::LocusSearch => locus_search(u, v),
+    }
+}
+
+///The isotemperature lines of Robertson's method, as reciprocal megakelvin,
+///u, v and the slope of the line.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const ROBERTSON: [[f64; 4]; 31] = [
+    [0.0, 0.18006, 0.26352, -0.24341],
+    [10.0, 0.18066, 0.26589, -0.25479],
+    [20.0, 0.18133, 0.26846, -0.26876],
+    [30.0, 0.18208, 0.27119, -0.28539],
+    [40.0, 0.18293, 0.27407, -0.3047],
+    [50.0, 0.18388, 0.27709, -0.32675],
+    [60.0, 0.18494, 0.28021, -0.35156],
+    [70.0, 0.18611, 0.28342, -0.37915],
+    [80.0, 0.18740, 0.28668, -0.40955],
+    [90.0, 0.18880, 0.28997, -0.44278],
+    [100.0, 0.19032, 0.29326, -0.47888],
+    [125.0, 0.19462, 0.30141, -0.58204],
+    [150.0, 0.19962, 0.30921, -0.70471],
+    [175.0, 0.20525, 0.31647, -0.84901],
+    [200.0, 0.21142, 0.32312, -1.0182],
+    [225.0, 0.21807, 0.32909, -1.2168],
+    [250.0, 0.22511, 0.33439, -1.4512],
+    [275.0, 0.23247, 0.33904, -1.7298],
+    [300.0, 0.24010, 0.34308, -2.0637],
+    [325.0, 0.24792, 0.34655, -2.4681],
+    [350.0, 0.25591, 0.34951, -2.9641],
+    [375.0, 0.26400, 0.35200, -3.5814],
+    [400.0, 0.27218, 0.35407, -4.3633],
+    [425.0, 0.28039, 0.35577, -5.3762],
+    [450.0, 0.28863, 0.35714, -6.7262],
+    [475.0, 0.29685, 0.35823, -8.5955],
+    [500.0, 0.30505, 0.35907, -11.324],
+    [525.0, 0.31320, 0.35968, -15.628],
+    [550.0, 0.32129, 0.36011, -23.325],
+    [575.0, 0.32931, 0.36038, -40.77],
+    [600.0, 0.33724, 0.36051, -116.45],
+];
+
+fn robertson<T: Component + Float>(u: T, v: T) -> Option<Temperature<T>> {
+    let distance = |row: &[f64; 4]| {
+        let (row_u, row_v, slope): (T, T, T) = (cast(row[1]), cast(row[2]), cast(row[3]));
+        ((v - row_v) - slope * (u - row_u)) / (T::one() + slope * slope).sqrt()
+    };
+
+    let mut previous = distance(&ROBERTSON[0]);
+    for (index, row) in ROBERTSON.iter().enumerate().skip(1) {
+        let current = distance(row);
+
+        if current == T::zero() || current.signum() != previous.signum() {
+            let last = &ROBERTSON[index - 1];
+            let fraction = previous / (previous - current);
+            let interpolate = |a: f64, b: f64| cast::<T, _>(a) + (cast::<T, _>(b) - cast(a)) * fraction;
+
+            let reciprocal = interpolate(last[0], row[0]);
+            let cct = cast::<T, _>(1.0e6) / reciprocal;
+
+            let (locus_u, locus_v) = planckian_uv(cct);
+            return Some(Temperature {
+                cct: cct,
+                duv: duv(u, v, locus_u, locus_v),
+            });
+        }
+
+        previous = current;
+    }
+
+    None
+}
+
+///The range of temperatures that is searched by the locus search.
+const SEARCH_RANGE: (f64, f64) = (1000.0, 100000.0);
+
+fn locus_search<T: Component + Float>(u: T, v: T) -> Option<Temperature<T>> {
+    //The squared distance is used, since it's smooth at the minimum.
+    let distance = |cct: T| {
+        let (locus_u, locus_v) = planckian_uv(cct);
+        (u - locus_u).powi(2) + (v - locus_v).powi(2)
+    };
+
+    //Search logarithmically spaced temperatures, narrowing the range around
+    //the closest one until the steps are small enough.
+    let steps: i32 = 15;
+    let (mut low, mut high): (T, T) = (cast(SEARCH_RANGE.0), cast(SEARCH_RANGE.1));
+    let mut closest = low;
+    let mut step_ratio = (high / low).powf(T::one() / T::from(steps - 1).unwrap());
+
+    for _ in 0..12 {
+        let mut best_distance = T::infinity();
+        let mut best_index = 0;
+
+        for i in 0..steps {
+            let cct = low * step_ratio.powi(i);
+            let current = distance(cct);
+            if current < best_distance {
+                best_distance = current;
+                best_index = i;
+            }
+        }
+
+        closest = low * step_ratio.powi(best_index);
+        if closest <= cast::<T, _>(SEARCH_RANGE.0) * cast(1.0001)
+            || closest >= cast::<T, _>(SEARCH_RANGE.1) * cast(0.9999)
+        {
+            return None;
+        }
+
+        if step_ratio < cast(1.001) {
+            break;
+        }
+
+        low = closest / step_ratio;
+        high = closest * step_ratio;
+        step_ratio = (high / low).powf(T::one() / T::from(steps - 1).unwrap());
+    }
+
+    //Fit a parabola through the distances around the closest temperature,
+    //on a logarithmic scale where the samples are evenly spaced.
+    let d1 = distance(closest / step_ratio);
+    let d2 = distance(closest);
+    let d3 = distance(closest * step_ratio);
+    let curvature = d1 - d2 * cast(2.0) + d3;
+
+    let cct = if curvature > T::zero() {
+        let offset = (d1 - d3) / (curvature * cast(2.0));
+        closest * step_ratio.powf(offset.max(-T::one()).min(T::one()))
+    } else {
+        closest
+    };
+
+    let (locus_u, locus_v) = planckian_uv(cct);
+    Some(Temperature {
+        cct: cct,
+        duv: duv(u, v, locus_u, locus_v),
+    })
+}
+
+///The range of temperatures that is tabulated by Ohno's method.
+const OHNO_RANGE: (f64, f64) = (1000.0, 100000.0);
+
+///The number of intervals in each cascaded table of Ohno's method.
+const OHNO_CASCADE_STEPS: usize = 10;
+
+///The relative temperature step where Ohno's cascade stops.
+const OHNO_PRECISION: f64 = 0.0001;
+
+fn ohno<T: Component + Float>(u: T, v: T) -> Option<Temperature<T>> {
+    //A row in the table of the Planckian locus, with the temperature, the
+    //chromaticity and the distance to the color.
+    let row = |cct: T| {
+        let (locus_u, locus_v) = planckian_uv(cct);
+        let distance = ((u - locus_u).powi(2) + (v - locus_v).powi(2)).sqrt();
+        (cct, locus_u, locus_v, distance)
+    };
+    let closest = |table: &[(T, T, T, T)]| {
+        let mut index = 0;
+        for (i, entry) in table.iter().enumerate() {
+            if entry.3 < table[index].3 {
+                index = i;
+            }
+        }
+        index
+    };
+
+    //The initial table, with 1% steps.
+    let (low, high): (T, T) = (cast(OHNO_RANGE.0), cast(OHNO_RANGE.1));
+    let step: T = cast(1.01);
+    let mut table = Vec::new();
+    let mut cct = low;
+    while cct <= high {
+        table.push(row(cct));
+        cct = cct * step;
+    }
+
+    let mut index = closest(&table);
+    if index == 0 || index == table.len() - 1 {
+        return None;
+    }
+
+    //Cascade into finer tables between the neighbors of the closest row.
+    while (table[index + 1].0 - table[index - 1].0) / table[index].0 > cast(OHNO_PRECISION) {
+        let (start, end) = (table[index - 1].0, table[index + 1].0);
+        let interval = (end - start) / cast(OHNO_CASCADE_STEPS);
+        table = (0..OHNO_CASCADE_STEPS + 1)
+            .map(|i| row(start + interval * cast(i as f64)))
+            .collect();
+        index = clamp(closest(&table), 1, OHNO_CASCADE_STEPS - 1);
+    }
+
+    let (t0, u0, v0, d0) = table[index - 1];
+    let (t1, _, _, d1) = table[index];
+    let (t2, u2, v2, d2) = table[index + 1];
+
+    //The triangular solution.
+    let length = ((u2 - u0).powi(2) + (v2 - v0).powi(2)).sqrt();
+    let x = (d0 * d0 - d2 * d2 + length * length) / (length * cast(2.0));
+    let cct = t0 + (t2 - t0) * x / length;
+    let locus_v = v0 + (v2 - v0) * x / length;
+    let sign = if v < locus_v { -T::one() } else { T::one() };
+    let duv = (d0 * d0 - x * x).max(T::zero()).sqrt() * sign;
+
+    if duv.abs() < cast(0.002) {
+        return Some(Temperature { cct: cct, duv: duv });
+    }
+
+    //The parabolic solution, further away from the locus.
+    let denominator = (t2 - t1) * (t0 - t2) * (t1 - t0);
+    let a = (t0 * (d2 - d1) + t1 * (d0 - d2) + t2 * (d1 - d0)) / denominator;
+    let b = -(t0 * t0 * (d2 - d1) + t1 * t1 * (d0 - d2) + t2 * t2 * (d1 - d0)) / denominator;
+    let c = -(d0 * (t2 - t1) * t1 * t2 + d1 * (t0 - t2) * t0 * t2 + d2 * (t1 - t0) * t0 * t1)
+        / denominator;
+    let cct = -b / (a * cast(2.0));
+
+    Some(Temperature {
+        cct: cct,
+        duv: (a * cct * cct + b * cct + c) * sign,
+    })
+}
+
+fn duv<T: Float>(u: T, v: T, locus_u: T, locus_v: T) -> T {
+    let distance = ((u - locus_u).powi(2) + (v - locus_v).powi(2)).sqrt();
+
+    if v < locus_v {
+        -distance
+    } else {
+        distance
+    }
+}
+
+///The CIE 1960 UCS coordinates of the Planckian locus at `cct`.
+fn planckian_uv<T: Float>(cct: T) -> (T, T) {
+    let mut xyz = [T::zero(); 3];
+
+    for (i, cmf) in CIE_1931_2.iter().enumerate() {
+        let wavelength: T = cast(CMF_START + CMF_INTERVAL * i as f64);
+        let power = cie_data::planck(cct, wavelength);
+
+        for (sum, value) in xyz.iter_mut().zip(cmf.iter()) {
+            *sum = *sum + power * cast(*value);
+        }
+    }
+
+    let denominator = xyz[0] + xyz[1] * cast(15.0) + xyz[2] * cast(3.0);
+    (
+        xyz[0] * cast(4.0) / denominator,
+        xyz[1] * cast(6.0) / denominator,
+    )
+}
+
+fn uv_to_yxy<Wp: WhitePoint, T: Component + Float>(u: T, v: T) -> Yxy<Wp, T> {
+    let denominator = u * cast(2.0) - v * cast(8.0) + cast(4.0);
+    Yxy::with_wp(
+        u * cast(3.0) / denominator,
+        v * cast(2.0) / denominator,
+        T::one(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use {Xyz, Yxy};
+    use white_point::{A, D50, D65, WhitePoint};
+    use super::{daylight, estimate, planckian, planckian_with_duv, uv_to_yxy, Method};
+
+    #[test]
+    fn planckian_locus() {
+        let a: Yxy<A, f64> = planckian(2856.0 * 1.4388 / 1.4380);
+        assert_relative_eq!(a.x, 0.44757, epsilon = 0.0002);
+        assert_relative_eq!(a.y, 0.40745, epsilon = 0.0002);
+
+        let hot: Yxy<A, f64> = planckian(10000.0);
+        assert_relative_eq!(hot.x, 0.2807, epsilon = 0.0002);
+        assert_relative_eq!(hot.y, 0.2884, epsilon = 0.0002);
+    }
+
+    #[test]
+    fn daylight_locus() {
+        let d65: Yxy<D65, f64> = daylight(6504.0);
+        let d50: Yxy<D50, f64> = daylight(5003.0);
+
+        assert_relative_eq!(d65.x, 0.31271, epsilon = 0.00005);
+        assert_relative_eq!(d65.y, 0.32902, epsilon = 0.0002);
+        assert_relative_eq!(d50.x, 0.34567, epsilon = 0.00005);
+        assert_relative_eq!(d50.y, 0.35850, epsilon = 0.0002);
+    }
+
+    #[test]
+    fn estimate_d65() {
+        let d65: Xyz<D65, f64> = D65::get_xyz();
+
+        for &method in &[Method::Robertson, Method::Ohno, Method::LocusSearch] {
+            let estimate = estimate(d65, method).unwrap();
+            assert_relative_eq!(estimate.cct, 6504.0, epsilon = 5.0);
+            assert_relative_eq!(estimate.duv, 0.0032, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for &cct in &[1800.0f64, 2700.0, 4000.0, 6500.0, 10000.0, 20000.0] {
+            for &duv in &[-0.01f64, 0.0, 0.005] {
+                let white: Yxy<D65, f64> = planckian_with_duv(cct, duv);
+
+                let ohno = estimate(white, Method::Ohno).unwrap();
+                assert_relative_eq!(ohno.cct, cct, max_relative = 0.0002);
+                assert_relative_eq!(ohno.duv, duv, epsilon = 0.00001);
+
+                let search = estimate(white, Method::LocusSearch).unwrap();
+                assert_relative_eq!(search.cct, cct, max_relative = 0.0005);
+                assert_relative_eq!(search.duv, duv, epsilon = 0.00001);
+
+                let robertson = estimate(white, Method::Robertson).unwrap();
+                assert_relative_eq!(robertson.cct, cct, max_relative = 0.005);
+                assert_relative_eq!(robertson.duv, duv, epsilon = 0.0002);
+            }
+        }
+    }
+
+    #[test]
+    fn ohno_reference() {
+        //The example of the colour-science implementation, which gives
+        //6507.47 K and 0.003223 with the 1 nm color matching functions. The
+        //5 nm table that is used here moves the locus slightly.
+        let yxy: Yxy<D65, f64> = uv_to_yxy(0.1978, 0.3122);
+        let estimate = estimate(yxy, Method::Ohno).unwrap();
+        assert_relative_eq!(estimate.cct, 6507.47, epsilon = 2.0);
+        assert_relative_eq!(estimate.duv, 0.003223, epsilon = 0.00002);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let black: Xyz<D65, f64> = Xyz::with_wp(0.0, 0.0, 0.0);
+        assert!(estimate(black, Method::LocusSearch).is_none());
+
+        let candle: Yxy<D65, f64> = planckian(1200.0);
+        assert!(estimate(candle, Method::Robertson).is_none());
+        assert!(estimate(candle, Method::Ohno).is_some());
+        assert!(estimate(candle, Method::LocusSearch).is_some());
+    }
+}
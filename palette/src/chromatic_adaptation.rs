@@ -5,8 +5,11 @@
 //!for the stable appearance of object colours despite the wide variation of light which
 //!might be reflected from an object and observed by our eyes.
 //!
-//!This library provides three methods for chromatic adaptation Bradford (which is the default),
-//!VonKries and XyzScaling
+//!This library provides several methods for chromatic adaptation: Bradford (which is the default),
+//!VonKries, XyzScaling, CAT02, CAT16, Sharp, CMCCAT2000 and Fairchild. Any of them can also be
+//!used for incomplete adaptation, where the degree of adaptation depends on the viewing
+//!conditions. See [`Partial`](struct.Partial.html) and
+//![`degree_of_adaptation`](fn.degree_of_adaptation.html).
 //!
 //!```
 //!use palette::Xyz;
@@ -24,11 +27,16 @@
 //!```
 use num_traits::Float;
 
-use {cast, Component, FromColor, IntoColor, Xyz};
-use white_point::WhitePoint;
-use matrix::{multiply_xyz, Mat3, multiply_3x3};
+use std::marker::PhantomData;
+
+use {cast, clamp, Component, FromColor, IntoColor, Pixel, Xyz};
+use white_point::{Dynamic, DynamicWhitePoint, WhitePoint};
+use rgb::{Rgb, RgbSpace};
+use encoding::Linear;
+use matrix::{matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix, Mat3};
 
 ///Chromatic adaptation methods implemented in the library
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Method {
     ///Bradford chromatic adaptation method
     Bradford,
@@ -36,6 +44,103 @@ pub enum Method {
     VonKries,
     ///XyzScaling chromatic adaptation method
     XyzScaling,
+    ///CAT02 chromatic adaptation method, from CIECAM02
+    Cat02,
+    ///CAT16 chromatic adaptation method, from CAM16
+    Cat16,
+    ///Sharp chromatic adaptation method, with spectrally sharpened cone responses
+    Sharp,
+    ///CMCCAT2000 chromatic adaptation method
+    CmcCat2000,
+    ///Fairchild chromatic adaptation method
+    Fairchild,
+}
+
+impl Method {
+    ///Use this method for incomplete adaptation with the degree of adaptation
+    ///`degree`, where `0.0` is no adaptation and `1.0` is complete
+    ///adaptation.
+    pub fn with_degree<T: Float>(self, degree: T) -> Partial<Method, T> {
+        Partial {
+            method: self,
+            degree: degree,
+        }
+    }
+}
+
+///Incomplete chromatic adaptation, using the cone response matrices of
+///`method`.
+///
+///The degree of adaptation is `1.0` for complete adaptation, where the source
+///white is mapped to the destination white, and `0.0` for no adaptation at all.
+///Values in between model an observer that only partly discounts the
+///illuminant, as in CIECAM02 and CAM16.
+///
+///```
+///use palette::Xyz;
+///use palette::white_point::{A, D65};
+///use palette::chromatic_adaptation::{degree_of_adaptation, AdaptInto, Method, Surround};
+///
+///let degree = degree_of_adaptation(20.0, Surround::Average);
+///let color = Xyz::<A, f32>::with_wp(0.3, 0.2, 0.1);
+///let adapted: Xyz<D65, f32> = color.adapt_into_using(Method::Cat16.with_degree(degree));
+///```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Partial<M, T> {
+    ///The chromatic adaptation method.
+    pub method: M,
+    ///The degree of adaptation, from `0.0` to `1.0`.
+    pub degree: T,
+}
+
+impl<M, Swp, Dwp, T> TransformMatrix<Swp, Dwp, T> for Partial<M, T>
+where
+    T: Component + Float,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+    M: TransformMatrix<Swp, Dwp, T>,
+{
+    fn get_cone_response(&self) -> ConeResponseMatrices<T> {
+        self.method.get_cone_response()
+    }
+
+    fn get_degree_of_adaptation(&self) -> T {
+        self.degree * self.method.get_degree_of_adaptation()
+    }
+}
+
+///The surround of a viewing condition, which affects the degree of adaptation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Surround {
+    ///An average surround, such as viewing surface colors.
+    Average,
+    ///A dim surround, such as viewing television.
+    Dim,
+    ///A dark surround, such as using a projector in a dark room.
+    Dark,
+}
+
+impl Surround {
+    ///The factor for the degree of adaptation, `F`, as in CIECAM02.
+    pub fn factor<T: Float>(&self) -> T {
+        match *self {
+            Surround::Average => cast(1.0),
+            Surround::Dim => cast(0.9),
+            Surround::Dark => cast(0.8),
+        }
+    }
+}
+
+///Computes the degree of adaptation, as in CIECAM02 and CAM16, from the
+///luminance of the adapting field in cd/m² and the surround.
+///
+///The result is between `0.0` and `1.0`, and can be used with
+///[`Partial`](struct.Partial.html). The adapting luminance is often taken to
+///be 20% of the luminance of a white object in the scene.
+pub fn degree_of_adaptation<T: Float>(adapting_luminance: T, surround: Surround) -> T {
+    let exponent = (-adapting_luminance - cast(42.0)) / cast(92.0);
+    let degree = surround.factor::<T>() * (T::one() - exponent.exp() / cast(3.6));
+    clamp(degree, T::zero(), T::one())
 }
 
 ///Holds the matrix coeffecients for the chromatic adaptation methods
@@ -57,33 +162,83 @@ where
     ///Get the cone response functions for the chromatic adaptation method
     fn get_cone_response(&self) -> ConeResponseMatrices<T>;
 
+    ///Get the degree of adaptation, where `1.0` is complete adaptation. This
+    ///is `1.0` by default.
+    fn get_degree_of_adaptation(&self) -> T {
+        T::one()
+    }
+
     ///Generates a 3x3 transformation matrix to convert color from one reference white point
     ///to another with the given cone_response
     fn generate_transform_matrix(&self) -> Mat3<T> {
         let s_wp: Xyz<Swp, T> = Swp::get_xyz();
         let t_wp: Xyz<Dwp, T> = Dwp::get_xyz();
-        let adapt = self.get_cone_response();
-
-        let resp_src: Xyz<Swp, _> = multiply_xyz(&adapt.ma, &s_wp);
-        let resp_dst: Xyz<Dwp, _> = multiply_xyz(&adapt.ma, &t_wp);
-        let z = T::zero();
-        let resp = [
-            resp_dst.x / resp_src.x,
-            z,
-            z,
-            z,
-            resp_dst.y / resp_src.y,
-            z,
-            z,
-            z,
-            resp_dst.z / resp_src.z,
-        ];
-
-        let tmp = multiply_3x3(&resp, &adapt.ma);
-        multiply_3x3(&adapt.inv_ma, &tmp)
+        partial_adaptation_matrix(
+            &self.get_cone_response(),
+            &s_wp,
+            &t_wp,
+            self.get_degree_of_adaptation(),
+        )
     }
 }
 
+///Generates a 3x3 transformation matrix to convert color from the source
+///white to the destination white, using the given cone response matrices.
+///
+///The white points are given as tristimulus values, which makes it possible
+///to adapt between white points that are only known at runtime, such as an
+///estimated scene illuminant.
+pub fn adaptation_matrix<Swp, Dwp, T>(
+    adapt: &ConeResponseMatrices<T>,
+    src_white: &Xyz<Swp, T>,
+    dst_white: &Xyz<Dwp, T>,
+) -> Mat3<T>
+where
+    T: Component + Float,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+{
+    partial_adaptation_matrix(adapt, src_white, dst_white, T::one())
+}
+
+///Generates a 3x3 transformation matrix for incomplete adaptation from the
+///source white to the destination white, using the given cone response
+///matrices.
+///
+///A `degree` of `1.0` gives the same result as
+///[`adaptation_matrix`](fn.adaptation_matrix.html), while `0.0` only scales
+///the color by the ratio between the luminances of the white points.
+pub fn partial_adaptation_matrix<Swp, Dwp, T>(
+    adapt: &ConeResponseMatrices<T>,
+    src_white: &Xyz<Swp, T>,
+    dst_white: &Xyz<Dwp, T>,
+    degree: T,
+) -> Mat3<T>
+where
+    T: Component + Float,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+{
+    let resp_src: Xyz<Swp, _> = multiply_xyz(&adapt.ma, src_white);
+    let resp_dst: Xyz<Dwp, _> = multiply_xyz(&adapt.ma, dst_white);
+    let luminance = (T::one() - degree) * dst_white.y / src_white.y;
+    let z = T::zero();
+    let resp = [
+        degree * resp_dst.x / resp_src.x + luminance,
+        z,
+        z,
+        z,
+        degree * resp_dst.y / resp_src.y + luminance,
+        z,
+        z,
+        z,
+        degree * resp_dst.z / resp_src.z + luminance,
+    ];
+
+    let tmp = multiply_3x3(&resp, &adapt.ma);
+    multiply_3x3(&adapt.inv_ma, &tmp)
+}
+
 impl<Swp, Dwp, T> TransformMatrix<Swp, Dwp, T> for Method
 where
     T: Component + Float,
@@ -129,6 +284,66 @@ where
                              ],
                 }
             }
+             Method::Cat02 => {
+                ConeResponseMatrices::<T> {
+                    ma: [cast(0.7328000), cast(0.4296000), cast(-0.1624000),
+                         cast(-0.7036000), cast(1.6975000), cast(0.0061000),
+                         cast(0.0030000), cast(0.0136000), cast(0.9834000)
+                         ],
+                    inv_ma: [cast(1.0961238), cast(-0.2788690), cast(0.1827452),
+                             cast(0.4543690), cast(0.4735332), cast(0.0720978),
+                             cast(-0.0096276), cast(-0.0056980), cast(1.0153256)
+                             ],
+                }
+            }
+             Method::Cat16 => {
+                ConeResponseMatrices::<T> {
+                    ma: [cast(0.4012880), cast(0.6501730), cast(-0.0514610),
+                         cast(-0.2502680), cast(1.2044140), cast(0.0458540),
+                         cast(-0.0020790), cast(0.0489520), cast(0.9531270)
+                         ],
+                    inv_ma: [cast(1.8620679), cast(-1.0112546), cast(0.1491868),
+                             cast(0.3875265), cast(0.6214474), cast(-0.0089740),
+                             cast(-0.0158415), cast(-0.0341229), cast(1.0499644)
+                             ],
+                }
+            }
+             Method::Sharp => {
+                ConeResponseMatrices::<T> {
+                    ma: [cast(1.2694000), cast(-0.0988000), cast(-0.1706000),
+                         cast(-0.8364000), cast(1.8006000), cast(0.0357000),
+                         cast(0.0297000), cast(-0.0315000), cast(1.0018000)
+                         ],
+                    inv_ma: [cast(0.8156333), cast(0.0471548), cast(0.1372166),
+                             cast(0.3791144), cast(0.5769424), cast(0.0440009),
+                             cast(-0.0122601), cast(0.0167431), cast(0.9955188)
+                             ],
+                }
+            }
+             Method::CmcCat2000 => {
+                ConeResponseMatrices::<T> {
+                    ma: [cast(0.7982000), cast(0.3389000), cast(-0.1371000),
+                         cast(-0.5918000), cast(1.5512000), cast(0.0406000),
+                         cast(0.0008000), cast(0.0239000), cast(0.9753000)
+                         ],
+                    inv_ma: [cast(1.0764500), cast(-0.2376624), cast(0.1612123),
+                             cast(0.4109643), cast(0.5543418), cast(0.0346939),
+                             cast(-0.0109538), cast(-0.0133894), cast(1.0243431)
+                             ],
+                }
+            }
+             Method::Fairchild => {
+                ConeResponseMatrices::<T> {
+                    ma: [cast(0.8562000), cast(0.3372000), cast(-0.1934000),
+                         cast(-0.8360000), cast(1.8327000), cast(0.0033000),
+                         cast(0.0357000), cast(-0.0469000), cast(1.0112000)
+                         ],
+                    inv_ma: [cast(0.9873999), cast(-0.1768250), cast(0.1894251),
+                             cast(0.4504351), cast(0.4649329), cast(0.0846320),
+                             cast(-0.0139683), cast(0.0278066), cast(0.9861618)
+                             ],
+                }
+            }
         }
     }
 }
@@ -201,12 +416,244 @@ where
     }
 }
 
+///A chromatic adaptation from the source white point (Swp) to the destination
+///white point (Dwp), with a precomputed transformation matrix.
+///
+///[`AdaptFrom`](trait.AdaptFrom.html) and [`AdaptInto`](trait.AdaptInto.html)
+///compute the transformation matrix for every color. An `Adapter` computes it
+///once, which makes it better suited for adapting many colors, such as whole
+///images.
+///
+///```
+///use palette::{LinSrgb, Xyz};
+///use palette::white_point::{D50, D65};
+///use palette::chromatic_adaptation::{Adapter, Method};
+///
+///let adapter = Adapter::<D65, D50, f32>::with_method(Method::Cat02);
+///let adapted: Xyz<D50, f32> = adapter.adapt(LinSrgb::new(0.8, 0.4, 0.1));
+///
+/////A buffer of XYZ components, adapted in place
+///let mut image = [0.3, 0.2, 0.1, 0.2, 0.3, 0.4];
+///adapter.adapt_raw(&mut image);
+///```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Adapter<Swp, Dwp, T> {
+    matrix: Mat3<T>,
+    white_points: PhantomData<(Swp, Dwp)>,
+}
+
+impl<Swp, Dwp, T> Adapter<Swp, Dwp, T>
+where
+    T: Component + Float,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+{
+    ///Create an adapter that uses the Bradford method.
+    pub fn new() -> Self {
+        Self::with_method(Method::Bradford)
+    }
+
+    ///Create an adapter that uses the specified method.
+    pub fn with_method<M: TransformMatrix<Swp, Dwp, T>>(method: M) -> Self {
+        Adapter {
+            matrix: method.generate_transform_matrix(),
+            white_points: PhantomData,
+        }
+    }
+
+    ///The transformation matrix, which is applied to XYZ colors.
+    pub fn matrix(&self) -> &Mat3<T> {
+        &self.matrix
+    }
+
+    ///Convert the source color to the destination color.
+    pub fn adapt<S, D>(&self, color: S) -> D
+    where
+        S: IntoColor<Swp, T>,
+        D: FromColor<Dwp, T>,
+    {
+        D::from_xyz(self.adapt_xyz(color.into_xyz()))
+    }
+
+    ///Convert a source XYZ color to the destination white point.
+    pub fn adapt_xyz(&self, color: Xyz<Swp, T>) -> Xyz<Dwp, T> {
+        multiply_xyz(&self.matrix, &color)
+    }
+
+    ///Convert each color in `source` and write the results to `destination`.
+    ///
+    ///## Panics
+    ///
+    ///Panics if the slices have different lengths.
+    pub fn adapt_slice<S, D>(&self, source: &[S], destination: &mut [D])
+    where
+        S: IntoColor<Swp, T> + Clone,
+        D: FromColor<Dwp, T>,
+    {
+        assert_eq!(source.len(), destination.len());
+
+        for (src, dst) in source.iter().zip(destination) {
+            *dst = self.adapt(src.clone());
+        }
+    }
+
+    ///Convert a buffer of raw XYZ components in place, as if it was cast with
+    ///[`Pixel::from_raw_slice_mut`](../trait.Pixel.html#method.from_raw_slice_mut).
+    ///
+    ///## Panics
+    ///
+    ///Panics if the length of the buffer is not a multiple of 3.
+    pub fn adapt_raw(&self, buffer: &mut [T]) {
+        for color in Xyz::<Swp, T>::from_raw_slice_mut(buffer) {
+            let adapted = self.adapt_xyz(*color);
+            color.x = adapted.x;
+            color.y = adapted.y;
+            color.z = adapted.z;
+        }
+    }
+
+    ///Combine the adaptation with the conversions from linear RGB in the
+    ///source space, and to linear RGB in the destination space, into a single
+    ///matrix.
+    pub fn rgb<Ss, Ds>(&self) -> RgbAdapter<Ss, Ds, T>
+    where
+        Ss: RgbSpace<WhitePoint = Swp>,
+        Ds: RgbSpace<WhitePoint = Dwp>,
+    {
+        let to_xyz = rgb_to_xyz_matrix::<Ss, T>();
+        let from_xyz = matrix_inverse(&rgb_to_xyz_matrix::<Ds, T>());
+
+        RgbAdapter {
+            matrix: multiply_3x3(&from_xyz, &multiply_3x3(&self.matrix, &to_xyz)),
+            spaces: PhantomData,
+        }
+    }
+}
+
+impl<T> Adapter<Dynamic, Dynamic, T>
+where
+    T: Component + Float,
+{
+    ///Create an adapter that uses the specified method, with white points that
+    ///are only known at runtime. The colors on both sides are tagged with the
+    ///[`Dynamic`](../white_point/struct.Dynamic.html) white point.
+    ///
+    ///```
+    ///use palette::Xyz;
+    ///use palette::white_point::{D50, D65, Dynamic, DynamicWhitePoint};
+    ///use palette::chromatic_adaptation::{Adapter, Method};
+    ///
+    ///let measured = DynamicWhitePoint::from_xyz(Xyz::<D65, f32>::with_wp(91.2, 95.0, 98.4));
+    ///let adapter = Adapter::with_white_points(Method::Bradford, &measured, &D50.into());
+    ///let white: Xyz<Dynamic, f32> = adapter.adapt_xyz(measured.get_xyz());
+    ///
+    /////The destination white is known to be D50 here
+    ///let white: Xyz<D50, f32> = Xyz::with_wp(white.x, white.y, white.z);
+    ///```
+    pub fn with_white_points<M: TransformMatrix<Dynamic, Dynamic, T>>(
+        method: M,
+        src_white: &DynamicWhitePoint<T>,
+        dst_white: &DynamicWhitePoint<T>,
+    ) -> Self {
+        Adapter {
+            matrix: partial_adaptation_matrix(
+                &method.get_cone_response(),
+                &src_white.get_xyz::<Dynamic>(),
+                &dst_white.get_xyz::<Dynamic>(),
+                method.get_degree_of_adaptation(),
+            ),
+            white_points: PhantomData,
+        }
+    }
+}
+
+impl<Swp, Dwp, T> Default for Adapter<Swp, Dwp, T>
+where
+    T: Component + Float,
+    Swp: WhitePoint,
+    Dwp: WhitePoint,
+{
+    fn default() -> Self {
+        Adapter::new()
+    }
+}
+
+///A chromatic adaptation from linear RGB in the source space (Ss) to linear RGB
+///in the destination space (Ds), as a single precomputed matrix.
+///
+///It's created with [`Adapter::rgb`](struct.Adapter.html#method.rgb).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RgbAdapter<Ss, Ds, T> {
+    matrix: Mat3<T>,
+    spaces: PhantomData<(Ss, Ds)>,
+}
+
+impl<Ss, Ds, T> RgbAdapter<Ss, Ds, T>
+where
+    T: Component + Float,
+    Ss: RgbSpace,
+    Ds: RgbSpace,
+{
+    ///The transformation matrix, which is applied to linear RGB colors.
+    pub fn matrix(&self) -> &Mat3<T> {
+        &self.matrix
+    }
+
+    ///Convert a linear RGB color from the source space to the destination
+    ///space.
+    pub fn adapt(&self, color: Rgb<Linear<Ss>, T>) -> Rgb<Linear<Ds>, T> {
+        let m = &self.matrix;
+        Rgb::new(
+            m[0] * color.red + m[1] * color.green + m[2] * color.blue,
+            m[3] * color.red + m[4] * color.green + m[5] * color.blue,
+            m[6] * color.red + m[7] * color.green + m[8] * color.blue,
+        )
+    }
+
+    ///Convert each color in `source` and write the results to `destination`.
+    ///
+    ///## Panics
+    ///
+    ///Panics if the slices have different lengths.
+    pub fn adapt_slice(
+        &self,
+        source: &[Rgb<Linear<Ss>, T>],
+        destination: &mut [Rgb<Linear<Ds>, T>],
+    ) {
+        assert_eq!(source.len(), destination.len());
+
+        for (&src, dst) in source.iter().zip(destination) {
+            *dst = self.adapt(src);
+        }
+    }
+
+    ///Convert a buffer of raw linear RGB components in place, as if it was
+    ///cast with
+    ///[`Pixel::from_raw_slice_mut`](../trait.Pixel.html#method.from_raw_slice_mut).
+    ///
+    ///## Panics
+    ///
+    ///Panics if the length of the buffer is not a multiple of 3.
+    pub fn adapt_raw(&self, buffer: &mut [T]) {
+        for color in Rgb::<Linear<Ss>, T>::from_raw_slice_mut(buffer) {
+            let adapted = self.adapt(*color);
+            color.red = adapted.red;
+            color.green = adapted.green;
+            color.blue = adapted.blue;
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
 
-    use Xyz;
-    use white_point::{D50, D65, A, C};
-    use super::{AdaptFrom, AdaptInto, Method, TransformMatrix};
+    use {FromColor, Lab, LinSrgb, Xyz};
+    use white_point::{DynamicWhitePoint, D50, D65, A, C};
+    use rgb::{Rgb, RgbSpace};
+    use encoding::{Linear, Srgb};
+    use matrix::multiply_3x3;
+    use super::{degree_of_adaptation, AdaptFrom, AdaptInto, Adapter, Method, Surround,
+                TransformMatrix};
 
     #[test]
     fn d65_to_d50_matrix_xyz_scaling() {
@@ -280,4 +727,173 @@ mod test {
         let computed_xyz_scaling: Xyz<C, _> = input_a.adapt_into_using(Method::XyzScaling);
         assert_relative_eq!(expected_xyz_scaling, computed_xyz_scaling, epsilon = 0.0001);
     }
+
+    #[test]
+    fn d65_to_d50_matrix_cat02() {
+        let expected = [
+            1.0424827, 0.0308012, -0.0527444, 0.0221296, 1.0018822, -0.0210462, -0.0011630,
+            -0.0034171, 0.7620404,
+        ];
+        let computed = TransformMatrix::<D65, D50, _>::generate_transform_matrix(&Method::Cat02);
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.0001)
+        }
+    }
+
+    #[test]
+    fn inverse_cone_responses() {
+        let methods = [
+            Method::Bradford,
+            Method::VonKries,
+            Method::XyzScaling,
+            Method::Cat02,
+            Method::Cat16,
+            Method::Sharp,
+            Method::CmcCat2000,
+            Method::Fairchild,
+        ];
+
+        for method in &methods {
+            let cone_response = TransformMatrix::<D65, D50, f64>::get_cone_response(method);
+            let identity = multiply_3x3(&cone_response.inv_ma, &cone_response.ma);
+            for (i, &value) in identity.iter().enumerate() {
+                let expected = if i % 4 == 0 { 1.0 } else { 0.0 };
+                assert_relative_eq!(value, expected, epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn white_point_adaptation() {
+        let methods = [
+            Method::Cat02,
+            Method::Cat16,
+            Method::Sharp,
+            Method::CmcCat2000,
+            Method::Fairchild,
+        ];
+
+        for method in &methods {
+            let white: Xyz<D50, f64> = Xyz::<D65, f64>::with_wp(0.95047, 1.0, 1.08883)
+                .adapt_into_using(*method);
+            assert_relative_eq!(white, Xyz::with_wp(0.96422, 1.0, 0.82521), epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn partial_adaptation() {
+        let input = Xyz::<A, f64>::with_wp(0.315756, 0.162732, 0.015905);
+
+        let none: Xyz<C, f64> = input.adapt_into_using(Method::Cat02.with_degree(0.0));
+        assert_relative_eq!(none, Xyz::with_wp(input.x, input.y, input.z), epsilon = 0.000001);
+
+        let complete: Xyz<C, f64> = input.adapt_into_using(Method::Cat02.with_degree(1.0));
+        let expected: Xyz<C, f64> = input.adapt_into_using(Method::Cat02);
+        assert_relative_eq!(complete, expected, epsilon = 0.000001);
+
+        let half: Xyz<C, f64> = input.adapt_into_using(Method::Cat02.with_degree(0.5));
+        assert!(half.z > none.z && half.z < complete.z);
+    }
+
+    #[test]
+    fn degree_of_adaptation_from_viewing_conditions() {
+        //The example viewing conditions in CIE 159:2004
+        assert_relative_eq!(
+            degree_of_adaptation(318.31, Surround::Average),
+            0.9945,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            degree_of_adaptation(0.0, Surround::Dark),
+            0.8 * (1.0 - (-42.0f64 / 92.0).exp() / 3.6),
+            epsilon = 0.000001
+        );
+        assert!(
+            degree_of_adaptation(20.0, Surround::Dim) < degree_of_adaptation(20.0, Surround::Average)
+        );
+    }
+
+    #[test]
+    fn adapter_matches_adapt_into() {
+        let input = Xyz::<A, f64>::with_wp(0.315756, 0.162732, 0.015905);
+        let adapter = Adapter::<A, C, f64>::with_method(Method::VonKries);
+
+        let expected: Xyz<C, f64> = input.adapt_into_using(Method::VonKries);
+        assert_relative_eq!(adapter.adapt_xyz(input), expected);
+
+        let lab: Lab<C, f64> = adapter.adapt(input);
+        assert_relative_eq!(lab, Lab::from_xyz(expected), epsilon = 0.000001);
+    }
+
+    #[test]
+    fn adapter_slices() {
+        let adapter = Adapter::<D65, D50, f64>::new();
+        let source = [
+            Xyz::with_wp(0.2, 0.3, 0.4),
+            Xyz::with_wp(0.9, 0.8, 0.1),
+        ];
+
+        let mut destination = [Xyz::with_wp(0.0, 0.0, 0.0); 2];
+        adapter.adapt_slice(&source, &mut destination);
+
+        let mut raw = [0.2, 0.3, 0.4, 0.9, 0.8, 0.1];
+        adapter.adapt_raw(&mut raw);
+
+        for (i, (&src, &dst)) in source.iter().zip(&destination).enumerate() {
+            let expected: Xyz<D50, f64> = src.adapt_into();
+            assert_relative_eq!(dst, expected);
+            assert_relative_eq!(raw[i * 3], expected.x);
+            assert_relative_eq!(raw[i * 3 + 1], expected.y);
+            assert_relative_eq!(raw[i * 3 + 2], expected.z);
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct SrgbD50;
+
+    impl RgbSpace for SrgbD50 {
+        type Primaries = Srgb;
+        type WhitePoint = D50;
+    }
+
+    #[test]
+    fn fused_rgb_adapter() {
+        let adapter = Adapter::<D65, D50, f64>::with_method(Method::Cat16).rgb::<Srgb, SrgbD50>();
+        let color = LinSrgb::new(0.8, 0.4, 0.1);
+
+        let xyz: Xyz<D50, f64> = color.adapt_into_using(Method::Cat16);
+        let expected = Rgb::<Linear<SrgbD50>, f64>::from_xyz(xyz);
+        assert_relative_eq!(adapter.adapt(color), expected, epsilon = 0.000001);
+
+        let mut raw = [0.8, 0.4, 0.1];
+        adapter.adapt_raw(&mut raw);
+        assert_relative_eq!(raw[0], expected.red, epsilon = 0.000001);
+        assert_relative_eq!(raw[1], expected.green, epsilon = 0.000001);
+        assert_relative_eq!(raw[2], expected.blue, epsilon = 0.000001);
+
+        let white = adapter.adapt(LinSrgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Rgb::new(1.0, 1.0, 1.0), epsilon = 0.0001);
+    }
+
+    #[test]
+    fn adapter_with_white_points() {
+        let input = Xyz::<A, f64>::with_wp(0.315756, 0.162732, 0.015905);
+        let adapter = Adapter::with_white_points(
+            Method::Bradford,
+            &DynamicWhitePoint::from_static::<A>(),
+            &C.into(),
+        );
+        let expected: Xyz<C, f64> = input.adapt_into();
+        let adapted = adapter.adapt_xyz(Xyz::with_wp(input.x, input.y, input.z));
+        assert_relative_eq!(
+            adapted,
+            Xyz::with_wp(expected.x, expected.y, expected.z),
+            epsilon = 0.000001
+        );
+
+        let measured = DynamicWhitePoint::new(0.9, 1.0, 0.8);
+        let adapter = Adapter::with_white_points(Method::Cat16, &measured, &D50.into());
+        let white = adapter.adapt_xyz(measured.get_xyz());
+        assert_relative_eq!(white, Xyz::with_wp(0.96422, 1.0, 0.82521), epsilon = 0.000001);
+    }
 }
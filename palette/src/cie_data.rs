@@ -0,0 +1,485 @@
+//!Tabulated CIE data.
+
+use num_traits::Float;
+
+use cast;
+
+///The first wavelength of `CIE_1931_2`, in nm.
+pub const CMF_START: f64 = 380.0;
+///The wavelength interval of `CIE_1931_2`, in nm.
+pub const CMF_INTERVAL: f64 = 5.0;
+
+///The CIE 1931 2° standard observer color matching functions, x̄, ȳ and z̄,
+///from 380 nm to 780 nm in steps of 5 nm.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+pub const CIE_1931_2: [[f64; 3]; 81] = [
+    [0.001368, 0.000039, 0.006450],
+    [0.002236, 0.000064, 0.010550],
+    [0.004243, 0.000120, 0.020050],
+    [0.007650, 0.000217, 0.036210],
+    [0.014310, 0.000396, 0.067850],
+    [0.023190, 0.000640, 0.110200],
+    [0.043510, 0.001210, 0.207400],
+    [0.077630, 0.002180, 0.371300],
+    [0.134380, 0.004000, 0.645600],
+    [0.214770, 0.007300, 1.039050],
+    [0.283900, 0.011600, 1.385600],
+    [0.328500, 0.016840, 1.622960],
+    [0.348280, 0.023000, 1.747060],
+    [0.348060, 0.029800, 1.782600],
+    [0.336200, 0.038000, 1.772110],
+    [0.318700, 0.048000, 1.744100],
+    [0.290800, 0.060000, 1.669200],
+    [0.251100, 0.073900, 1.528100],
+    [0.195360, 0.090980, 1.287640],
+    [0.142100, 0.112600, 1.041900],
+    [0.095640, 0.139020, 0.812950],
+    [0.057950, 0.169300, 0.616200],
+    [0.032010, 0.208020, 0.465180],
+    [0.014700, 0.258600, 0.353300],
+    [0.004900, 0.323000, 0.272000],
+    [0.002400, 0.407300, 0.212300],
+    [0.009300, 0.503000, 0.158200],
+    [0.029100, 0.608200, 0.111700],
+    [0.063270, 0.710000, 0.078250],
+    [0.109600, 0.793200, 0.057250],
+    [0.165500, 0.862000, 0.042160],
+    [0.225750, 0.914850, 0.029840],
+    [0.290400, 0.954000, 0.020300],
+    [0.359700, 0.980300, 0.013400],
+    [0.433450, 0.994950, 0.008750],
+    [0.512050, 1.000000, 0.005750],
+    [0.594500, 0.995000, 0.003900],
+    [0.678400, 0.978600, 0.002750],
+    [0.762100, 0.952000, 0.002100],
+    [0.842500, 0.915400, 0.001800],
+    [0.916300, 0.870000, 0.001650],
+    [0.978600, 0.816300, 0.001400],
+    [1.026300, 0.757000, 0.001100],
+    [1.056700, 0.694900, 0.001000],
+    [1.062200, 0.631000, 0.000800],
+    [1.045600, 0.566800, 0.000600],
+    [1.002600, 0.503000, 0.000340],
+    [0.938400, 0.441200, 0.000240],
+    [0.854450, 0.381000, 0.000190],
+    [0.751400, 0.321000, 0.000100],
+    [0.642400, 0.265000, 0.000050],
+    [0.541900, 0.217000, 0.000030],
+    [0.447900, 0.175000, 0.000020],
+    [0.360800, 0.138200, 0.000010],
+    [0.283500, 0.107000, 0.000000],
+    [0.218700, 0.081600, 0.000000],
+    [0.164900, 0.061000, 0.000000],
+    [0.121200, 0.044580, 0.000000],
+    [0.087400, 0.032000, 0.000000],
+    [0.063600, 0.023200, 0.000000],
+    [0.046770, 0.017000, 0.000000],
+    [0.032900, 0.011920, 0.000000],
+    [0.022700, 0.008210, 0.000000],
+    [0.015840, 0.005723, 0.000000],
+    [0.011359, 0.004102, 0.000000],
+    [0.008111, 0.002929, 0.000000],
+    [0.005790, 0.002091, 0.000000],
+    [0.004109, 0.001484, 0.000000],
+    [0.002899, 0.001047, 0.000000],
+    [0.002049, 0.000740, 0.000000],
+    [0.001440, 0.000520, 0.000000],
+    [0.001000, 0.000361, 0.000000],
+    [0.000690, 0.000249, 0.000000],
+    [0.000476, 0.000172, 0.000000],
+    [0.000332, 0.000120, 0.000000],
+    [0.000235, 0.000085, 0.000000],
+    [0.000166, 0.000060, 0.000000],
+    [0.000117, 0.000042, 0.000000],
+    [0.000083, 0.000030, 0.000000],
+    [0.000059, 0.000021, 0.000000],
+    [0.000042, 0.000015, 0.000000],
+];
+
+///The CIE 1964 10° standard observer color matching functions, x̄₁₀, ȳ₁₀ and
+///z̄₁₀, from 380 nm to 780 nm in steps of 5 nm. It uses the same wavelengths
+///as `CIE_1931_2`.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+pub const CIE_1964_10: [[f64; 3]; 81] = [
+    [0.000160, 0.000017, 0.000705],
+    [0.000662, 0.000072, 0.002928],
+    [0.002362, 0.000253, 0.010482],
+    [0.007242, 0.000769, 0.032344],
+    [0.019110, 0.002004, 0.086011],
+    [0.043400, 0.004509, 0.197120],
+    [0.084736, 0.008756, 0.389366],
+    [0.140638, 0.014456, 0.656760],
+    [0.204492, 0.021391, 0.972542],
+    [0.264737, 0.029497, 1.282500],
+    [0.314679, 0.038676, 1.553480],
+    [0.357719, 0.049602, 1.798500],
+    [0.383734, 0.062077, 1.967280],
+    [0.386726, 0.074704, 2.027300],
+    [0.370702, 0.089456, 1.994800],
+    [0.342957, 0.106256, 1.900700],
+    [0.302273, 0.128201, 1.745370],
+    [0.254085, 0.152761, 1.554900],
+    [0.195618, 0.185190, 1.317560],
+    [0.132349, 0.219940, 1.030200],
+    [0.080507, 0.253589, 0.772125],
+    [0.041072, 0.297665, 0.570060],
+    [0.016172, 0.339133, 0.415254],
+    [0.005132, 0.395379, 0.302356],
+    [0.003816, 0.460777, 0.218502],
+    [0.015444, 0.531360, 0.159249],
+    [0.037465, 0.606741, 0.112044],
+    [0.071358, 0.685660, 0.082248],
+    [0.117749, 0.761757, 0.060709],
+    [0.172953, 0.823330, 0.043050],
+    [0.236491, 0.875211, 0.030451],
+    [0.304213, 0.923810, 0.020584],
+    [0.376772, 0.961988, 0.013676],
+    [0.451584, 0.982200, 0.007918],
+    [0.529826, 0.991761, 0.003988],
+    [0.616053, 0.999110, 0.001091],
+    [0.705224, 0.997340, 0.000000],
+    [0.793832, 0.982380, 0.000000],
+    [0.878655, 0.955552, 0.000000],
+    [0.951162, 0.915175, 0.000000],
+    [1.014160, 0.868934, 0.000000],
+    [1.074300, 0.825623, 0.000000],
+    [1.118520, 0.777405, 0.000000],
+    [1.134300, 0.720353, 0.000000],
+    [1.123990, 0.658341, 0.000000],
+    [1.089100, 0.593878, 0.000000],
+    [1.030480, 0.527963, 0.000000],
+    [0.950740, 0.461834, 0.000000],
+    [0.856297, 0.398057, 0.000000],
+    [0.754930, 0.339554, 0.000000],
+    [0.647467, 0.283493, 0.000000],
+    [0.535110, 0.228254, 0.000000],
+    [0.431567, 0.179828, 0.000000],
+    [0.343690, 0.140211, 0.000000],
+    [0.268329, 0.107633, 0.000000],
+    [0.204300, 0.081187, 0.000000],
+    [0.152568, 0.060281, 0.000000],
+    [0.112210, 0.044096, 0.000000],
+    [0.081261, 0.031800, 0.000000],
+    [0.057930, 0.022602, 0.000000],
+    [0.040851, 0.015905, 0.000000],
+    [0.028623, 0.011130, 0.000000],
+    [0.019941, 0.007749, 0.000000],
+    [0.013842, 0.005375, 0.000000],
+    [0.009577, 0.003718, 0.000000],
+    [0.006605, 0.002565, 0.000000],
+    [0.004553, 0.001768, 0.000000],
+    [0.003145, 0.001222, 0.000000],
+    [0.002175, 0.000846, 0.000000],
+    [0.001506, 0.000586, 0.000000],
+    [0.001045, 0.000407, 0.000000],
+    [0.000727, 0.000284, 0.000000],
+    [0.000508, 0.000199, 0.000000],
+    [0.000356, 0.000140, 0.000000],
+    [0.000251, 0.000098, 0.000000],
+    [0.000178, 0.000070, 0.000000],
+    [0.000126, 0.000050, 0.000000],
+    [0.000090, 0.000036, 0.000000],
+    [0.000065, 0.000025, 0.000000],
+    [0.000046, 0.000018, 0.000000],
+    [0.000033, 0.000013, 0.000000],
+];
+
+///The first wavelength of `DAYLIGHT_COMPONENTS`, in nm.
+pub const DAYLIGHT_START: f64 = 300.0;
+///The wavelength interval of `DAYLIGHT_COMPONENTS`, in nm.
+pub const DAYLIGHT_INTERVAL: f64 = 10.0;
+
+///The CIE daylight components S0, S1 and S2, from 300 nm to 830 nm in steps of
+///10 nm.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+pub const DAYLIGHT_COMPONENTS: [[f64; 3]; 54] = [
+    [0.04, 0.02, 0.0],
+    [6.0, 4.5, 2.0],
+    [29.6, 22.4, 4.0],
+    [55.3, 42.0, 8.5],
+    [57.3, 40.6, 7.8],
+    [61.8, 41.6, 6.7],
+    [61.5, 38.0, 5.3],
+    [68.8, 42.4, 6.1],
+    [63.4, 38.5, 3.0],
+    [65.8, 35.0, 1.2],
+    [94.8, 43.4, -1.1],
+    [104.8, 46.3, -0.5],
+    [105.9, 43.9, -0.7],
+    [96.8, 37.1, -1.2],
+    [113.9, 36.7, -2.6],
+    [125.6, 35.9, -2.9],
+    [125.5, 32.6, -2.8],
+    [121.3, 27.9, -2.6],
+    [121.3, 24.3, -2.6],
+    [113.5, 20.1, -1.8],
+    [113.1, 16.2, -1.5],
+    [110.8, 13.2, -1.3],
+    [106.5, 8.6, -1.2],
+    [108.8, 6.1, -1.0],
+    [105.3, 4.2, -0.5],
+    [104.4, 1.9, -0.3],
+    [100.0, 0.0, 0.0],
+    [96.0, -1.6, 0.2],
+    [95.1, -3.5, 0.5],
+    [89.1, -3.5, 2.1],
+    [90.5, -5.8, 3.2],
+    [90.3, -7.2, 4.1],
+    [88.4, -8.6, 4.7],
+    [84.0, -9.5, 5.1],
+    [85.1, -10.9, 6.7],
+    [81.9, -10.7, 7.3],
+    [82.6, -12.0, 8.6],
+    [84.9, -14.0, 9.8],
+    [81.3, -13.6, 10.2],
+    [71.9, -12.0, 8.3],
+    [74.3, -13.3, 9.6],
+    [76.4, -12.9, 8.5],
+    [63.3, -10.6, 7.0],
+    [71.7, -11.6, 7.6],
+    [77.0, -12.2, 8.0],
+    [65.2, -10.2, 6.7],
+    [47.7, -7.8, 5.2],
+    [68.6, -11.2, 7.4],
+    [65.0, -10.4, 6.8],
+    [66.0, -10.6, 7.0],
+    [61.0, -9.7, 6.4],
+    [53.3, -8.3, 5.5],
+    [58.9, -9.3, 6.1],
+    [61.9, -9.8, 6.5],
+];
+
+///The first wavelength of `FLUORESCENT`, in nm.
+pub const FLUORESCENT_START: f64 = 380.0;
+///The wavelength interval of `FLUORESCENT`, in nm.
+pub const FLUORESCENT_INTERVAL: f64 = 5.0;
+
+///The relative spectral power distributions of the CIE fluorescent
+///illuminants F1 to F12, from 380 nm to 780 nm in steps of 5 nm.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+#[cfg_attr(feature = "cargo-clippy", allow(approx_constant))]
+#[cfg_attr(clippy, allow(clippy::approx_constant))]
+pub const FLUORESCENT: [[f64; 81]; 12] = [
+    //F1
+    [
+        1.87, 2.36, 2.94, 3.47, 5.17, 19.49, 6.13, 6.24, 7.01, 7.79,
+        8.56, 43.67, 16.94, 10.72, 11.35, 11.89, 12.37, 12.75, 13.00, 13.15,
+        13.23, 13.17, 13.13, 12.85, 12.52, 12.20, 11.83, 11.50, 11.22, 11.05,
+        11.03, 11.18, 11.53, 27.74, 17.05, 13.55, 14.33, 15.01, 15.52, 18.29,
+        19.55, 15.48, 14.91, 14.15, 13.22, 12.19, 11.12, 10.03, 8.95, 7.96,
+        7.02, 6.20, 5.42, 4.73, 4.15, 3.64, 3.20, 2.81, 2.47, 2.18,
+        1.93, 1.72, 1.67, 1.43, 1.29, 1.19, 1.08, 0.96, 0.88, 0.81,
+        0.77, 0.75, 0.73, 0.68, 0.69, 0.64, 0.68, 0.69, 0.61, 0.52,
+        0.43,
+    ],
+    //F2
+    [
+        1.18, 1.48, 1.84, 2.15, 3.44, 15.69, 3.85, 3.74, 4.19, 4.62,
+        5.06, 34.98, 11.81, 6.27, 6.63, 6.93, 7.19, 7.40, 7.54, 7.62,
+        7.65, 7.62, 7.62, 7.45, 7.28, 7.15, 7.05, 7.04, 7.16, 7.47,
+        8.04, 8.88, 10.01, 24.88, 16.64, 14.59, 16.16, 17.56, 18.62, 21.47,
+        22.79, 19.29, 18.66, 17.73, 16.54, 15.21, 13.80, 12.36, 10.95, 9.65,
+        8.40, 7.32, 6.31, 5.43, 4.68, 4.02, 3.45, 2.96, 2.55, 2.19,
+        1.89, 1.64, 1.53, 1.27, 1.10, 0.99, 0.88, 0.76, 0.68, 0.61,
+        0.56, 0.54, 0.51, 0.47, 0.47, 0.43, 0.46, 0.47, 0.40, 0.33,
+        0.27,
+    ],
+    //F3
+    [
+        0.82, 1.02, 1.26, 1.44, 2.57, 14.36, 2.70, 2.45, 2.73, 3.00,
+        3.28, 31.85, 9.47, 4.02, 4.25, 4.44, 4.59, 4.72, 4.80, 4.86,
+        4.87, 4.85, 4.88, 4.77, 4.67, 4.62, 4.62, 4.73, 4.99, 5.48,
+        6.25, 7.34, 8.78, 23.82, 16.14, 14.59, 16.63, 18.49, 19.95, 23.11,
+        24.69, 21.41, 20.85, 19.93, 18.67, 17.22, 15.65, 14.04, 12.45, 10.95,
+        9.51, 8.27, 7.11, 6.09, 5.22, 4.45, 3.80, 3.23, 2.75, 2.33,
+        1.99, 1.70, 1.55, 1.27, 1.09, 0.96, 0.83, 0.71, 0.62, 0.54,
+        0.49, 0.46, 0.43, 0.39, 0.39, 0.35, 0.38, 0.39, 0.33, 0.28,
+        0.21,
+    ],
+    //F4
+    [
+        0.57, 0.70, 0.87, 0.98, 2.01, 13.75, 1.95, 1.59, 1.76, 1.93,
+        2.10, 30.28, 8.03, 2.55, 2.70, 2.82, 2.91, 2.99, 3.04, 3.08,
+        3.09, 3.09, 3.14, 3.06, 3.00, 2.98, 3.01, 3.14, 3.41, 3.90,
+        4.69, 5.81, 7.32, 22.59, 15.11, 13.88, 16.33, 18.68, 20.64, 24.28,
+        26.26, 23.28, 22.94, 22.14, 20.91, 19.43, 17.74, 16.00, 14.42, 12.56,
+        10.93, 9.52, 8.18, 7.01, 6.00, 5.11, 4.36, 3.69, 3.13, 2.64,
+        2.24, 1.91, 1.70, 1.39, 1.18, 1.03, 0.88, 0.74, 0.64, 0.54,
+        0.49, 0.46, 0.42, 0.37, 0.37, 0.33, 0.35, 0.36, 0.31, 0.26,
+        0.19,
+    ],
+    //F5
+    [
+        1.87, 2.35, 2.92, 3.45, 5.10, 18.91, 6.00, 6.11, 6.85, 7.58,
+        8.31, 40.76, 16.06, 10.32, 10.91, 11.40, 11.83, 12.17, 12.40, 12.54,
+        12.58, 12.52, 12.47, 12.20, 11.89, 11.61, 11.33, 11.10, 10.96, 10.97,
+        11.16, 11.54, 12.12, 27.78, 17.73, 14.47, 15.20, 15.77, 16.10, 18.54,
+        19.50, 15.39, 14.64, 13.72, 12.69, 11.57, 10.45, 9.35, 8.29, 7.32,
+        6.41, 5.63, 4.90, 4.26, 3.72, 3.25, 2.83, 2.49, 2.19, 1.93,
+        1.71, 1.52, 1.48, 1.26, 1.13, 1.05, 0.96, 0.85, 0.78, 0.72,
+        0.68, 0.67, 0.65, 0.61, 0.62, 0.59, 0.62, 0.64, 0.55, 0.47,
+        0.40,
+    ],
+    //F6
+    [
+        1.05, 1.31, 1.63, 1.90, 3.11, 14.80, 3.43, 3.30, 3.68, 4.07,
+        4.45, 32.61, 10.74, 5.48, 5.78, 6.03, 6.25, 6.41, 6.52, 6.58,
+        6.59, 6.56, 6.56, 6.42, 6.28, 6.20, 6.19, 6.30, 6.60, 7.12,
+        7.94, 9.07, 10.49, 25.22, 17.46, 15.63, 17.22, 18.53, 19.43, 21.97,
+        23.01, 19.41, 18.56, 17.42, 16.09, 14.64, 13.15, 11.68, 10.25, 8.95,
+        7.74, 6.69, 5.71, 4.87, 4.16, 3.55, 3.02, 2.57, 2.20, 1.87,
+        1.60, 1.37, 1.29, 1.05, 0.91, 0.81, 0.71, 0.61, 0.54, 0.48,
+        0.44, 0.43, 0.40, 0.37, 0.38, 0.35, 0.39, 0.41, 0.33, 0.26,
+        0.21,
+    ],
+    //F7
+    [
+        2.56, 3.18, 3.84, 4.53, 6.15, 19.37, 7.37, 7.05, 7.71, 8.41,
+        9.15, 44.14, 17.52, 11.35, 12.00, 12.58, 13.08, 13.45, 13.71, 13.88,
+        13.95, 13.93, 13.82, 13.64, 13.43, 13.25, 13.08, 12.93, 12.78, 12.60,
+        12.44, 12.33, 12.26, 29.52, 17.05, 12.44, 12.58, 12.72, 12.83, 15.46,
+        16.75, 12.83, 12.67, 12.45, 12.19, 11.89, 11.60, 11.35, 11.12, 10.95,
+        10.76, 10.42, 10.11, 10.04, 10.02, 10.11, 9.87, 8.65, 7.27, 6.44,
+        5.83, 5.41, 5.04, 4.57, 4.12, 3.77, 3.46, 3.08, 2.73, 2.47,
+        2.25, 2.06, 1.90, 1.75, 1.62, 1.54, 1.45, 1.32, 1.17, 0.99,
+        0.81,
+    ],
+    //F8
+    [
+        1.21, 1.50, 1.81, 2.13, 3.17, 13.08, 3.83, 3.45, 3.86, 4.42,
+        5.09, 34.10, 12.42, 7.68, 8.60, 9.46, 10.24, 10.84, 11.33, 11.71,
+        11.98, 12.17, 12.28, 12.32, 12.35, 12.44, 12.55, 12.68, 12.77, 12.72,
+        12.60, 12.43, 12.22, 28.96, 16.51, 11.79, 11.76, 11.77, 11.84, 14.61,
+        16.11, 12.34, 12.53, 12.72, 12.92, 13.12, 13.34, 13.61, 13.87, 14.07,
+        14.20, 14.16, 14.13, 14.34, 14.50, 14.46, 14.00, 12.58, 10.99, 9.98,
+        9.22, 8.62, 8.07, 7.39, 6.71, 6.16, 5.63, 5.03, 4.46, 4.02,
+        3.66, 3.36, 3.09, 2.85, 2.65, 2.51, 2.37, 2.15, 1.89, 1.61,
+        1.32,
+    ],
+    //F9
+    [
+        0.90, 1.12, 1.36, 1.60, 2.59, 12.80, 3.05, 2.56, 2.86, 3.30,
+        3.82, 32.62, 10.77, 5.84, 6.57, 7.25, 7.86, 8.35, 8.75, 9.06,
+        9.31, 9.48, 9.61, 9.68, 9.74, 9.88, 10.04, 10.26, 10.48, 10.63,
+        10.78, 10.96, 11.18, 27.71, 16.29, 12.28, 12.74, 13.21, 13.65, 16.57,
+        18.14, 14.55, 14.65, 14.66, 14.61, 14.50, 14.39, 14.40, 14.47, 14.62,
+        14.72, 14.55, 14.40, 14.58, 14.88, 15.51, 15.47, 13.20, 10.57, 9.18,
+        8.25, 7.57, 7.03, 6.35, 5.72, 5.25, 4.80, 4.29, 3.80, 3.43,
+        3.12, 2.86, 2.64, 2.43, 2.26, 2.14, 2.02, 1.83, 1.61, 1.38,
+        1.12,
+    ],
+    //F10
+    [
+        1.11, 0.63, 0.62, 0.57, 1.48, 12.16, 2.12, 2.70, 3.74, 5.14,
+        6.75, 34.39, 14.86, 10.40, 10.76, 10.67, 10.11, 9.27, 8.29, 7.29,
+        7.91, 16.64, 16.73, 10.44, 5.94, 3.34, 2.35, 1.88, 1.59, 1.47,
+        1.80, 5.71, 40.98, 73.69, 33.61, 8.24, 3.38, 2.47, 2.14, 4.86,
+        11.45, 14.79, 12.16, 8.97, 6.52, 8.31, 44.12, 34.55, 12.09, 12.15,
+        10.52, 4.43, 1.95, 2.19, 3.19, 2.77, 2.29, 2.00, 1.52, 1.35,
+        1.47, 1.79, 1.74, 1.02, 1.14, 3.32, 4.49, 2.05, 0.49, 0.24,
+        0.21, 0.21, 0.24, 0.24, 0.21, 0.17, 0.21, 0.22, 0.17, 0.12,
+        0.09,
+    ],
+    //F11
+    [
+        0.91, 0.63, 0.46, 0.37, 1.29, 12.68, 1.59, 1.79, 2.46, 3.33,
+        4.49, 33.94, 12.13, 6.95, 7.19, 7.12, 6.72, 6.13, 5.46, 4.79,
+        5.66, 14.29, 14.96, 8.97, 4.72, 2.33, 1.47, 1.10, 0.89, 0.83,
+        1.18, 4.90, 39.59, 72.84, 32.61, 7.52, 2.83, 1.96, 1.67, 4.43,
+        11.28, 14.76, 12.73, 9.74, 7.33, 9.72, 55.27, 42.58, 13.18, 13.16,
+        12.26, 5.11, 2.07, 2.34, 3.58, 3.01, 2.48, 2.14, 1.54, 1.33,
+        1.46, 1.94, 2.00, 1.20, 1.35, 4.10, 5.58, 2.51, 0.57, 0.27,
+        0.23, 0.21, 0.24, 0.24, 0.20, 0.24, 0.32, 0.26, 0.16, 0.12,
+        0.09,
+    ],
+    //F12
+    [
+        0.96, 0.64, 0.40, 0.33, 1.19, 12.48, 1.12, 0.94, 1.08, 1.37,
+        1.78, 29.05, 7.90, 2.65, 2.71, 2.65, 2.49, 2.33, 2.10, 1.91,
+        3.01, 10.83, 11.88, 6.88, 3.43, 1.49, 0.92, 0.71, 0.60, 0.63,
+        1.10, 4.56, 34.40, 65.40, 29.48, 7.16, 3.08, 2.47, 2.27, 5.09,
+        11.96, 15.32, 14.27, 11.86, 9.28, 12.31, 68.53, 53.02, 14.67, 14.38,
+        14.71, 6.46, 2.57, 2.75, 4.18, 3.44, 2.81, 2.42, 1.64, 1.36,
+        1.49, 2.14, 2.34, 1.42, 1.61, 5.04, 6.98, 3.19, 0.71, 0.30,
+        0.26, 0.23, 0.28, 0.28, 0.21, 0.17, 0.21, 0.19, 0.15, 0.10,
+        0.05,
+    ],
+];
+
+///Linearly interpolate a table with evenly spaced rows. Wavelengths outside
+///of the table give the first or last row.
+pub fn interpolate<T: Float>(table: &[[f64; 3]], start: f64, interval: f64, wavelength: T) -> [T; 3] {
+    let last = table.len() - 1;
+    let position = (wavelength.to_f64().unwrap() - start) / interval;
+
+    if position <= 0.0 {
+        return row(&table[0]);
+    }
+    if position >= last as f64 {
+        return row(&table[last]);
+    }
+
+    let index = position.floor() as usize;
+    let fraction = position - index as f64;
+    let low = &table[index];
+    let high = &table[index + 1];
+
+    [
+        cast(low[0] + (high[0] - low[0]) * fraction),
+        cast(low[1] + (high[1] - low[1]) * fraction),
+        cast(low[2] + (high[2] - low[2]) * fraction),
+    ]
+}
+
+fn row<T: Float>(row: &[f64; 3]) -> [T; 3] {
+    [cast(row[0]), cast(row[1]), cast(row[2])]
+}
+
+///The chromaticity of CIE daylight with the correlated color temperature
+///`cct`, in K. The formula is defined from 4000 K to 25000 K.
+pub fn daylight_chromaticity<T: Float>(cct: T) -> (T, T) {
+    let t = cct;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let x = if t <= cast(7000.0) {
+        cast::<T, _>(-4.6070e9) / t3 + cast::<T, _>(2.9678e6) / t2 + cast::<T, _>(0.09911e3) / t
+            + cast(0.244063)
+    } else {
+        cast::<T, _>(-2.0064e9) / t3 + cast::<T, _>(1.9018e6) / t2 + cast::<T, _>(0.24748e3) / t
+            + cast(0.237040)
+    };
+    let y = cast::<T, _>(-3.0) * x * x + cast::<T, _>(2.87) * x - cast(0.275);
+
+    (x, y)
+}
+
+///The relative spectral power of CIE daylight with the correlated color
+///temperature `cct`, in K, at `wavelength`, in nm. It's normalized to about
+///`100.0` at 560 nm.
+pub fn daylight<T: Float>(cct: T, wavelength: T) -> T {
+    let (x, y) = daylight_chromaticity(cct);
+    let m = cast::<T, _>(0.0241) + cast::<T, _>(0.2562) * x - cast::<T, _>(0.7341) * y;
+    let m1 = (cast::<T, _>(-1.3515) - cast::<T, _>(1.7703) * x + cast::<T, _>(5.9114) * y) / m;
+    let m2 = (cast::<T, _>(0.0300) - cast::<T, _>(31.4424) * x + cast::<T, _>(30.0717) * y) / m;
+
+    let s = interpolate(
+        &DAYLIGHT_COMPONENTS,
+        DAYLIGHT_START,
+        DAYLIGHT_INTERVAL,
+        wavelength,
+    );
+    s[0] + m1 * s[1] + m2 * s[2]
+}
+
+///The spectral radiance of a black body at the temperature `temperature`, in
+///K, at `wavelength`, in nm. It's normalized to `100.0` at 560 nm.
+pub fn planck<T: Float>(temperature: T, wavelength: T) -> T {
+    let radiance = |wavelength: T| {
+        let c2: T = cast(1.4388e-2);
+        let meters = wavelength * cast(1e-9);
+        T::one() / (meters.powi(5) * ((c2 / (meters * temperature)).exp() - T::one()))
+    };
+
+    radiance(wavelength) / radiance(cast(560.0)) * cast(100.0)
+}
@@ -0,0 +1,382 @@
+//!Perceptual color difference metrics.
+//!
+//!The metrics are all computed in CIE L\*a\*b\*, and are available both as
+//!functions of `Lab` values and through the [`ColorDifference`](trait.ColorDifference.html)
+//!trait, which is implemented for every color that can be converted to `Lab`.
+//!
+//!```
+//!use palette::{Lab, Srgb};
+//!use palette::color_difference::ColorDifference;
+//!
+//!let reference = Lab::new(50.0, 2.6772, -79.7751);
+//!let sample = Lab::new(50.0, 0.0, -82.7485);
+//!
+//!let delta_e: f32 = reference.delta_e_ciede2000(sample);
+//!assert!((delta_e - 2.0425).abs() < 0.0001);
+//!
+//!//Any color can be compared, as long as the white points match.
+//!let rgb_delta_e = Srgb::new(0.8, 0.2, 0.1).into_linear().delta_e_cie76(sample);
+//!```
+//!
+//!CIE94 and CMC l:c are not symmetric. The first color is the reference and
+//!the second is the sample.
+
+use num_traits::Float;
+
+use {cast, Component, IntoColor, Lab};
+use white_point::WhitePoint;
+
+///The application specific weightings of CIE94.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cie94Weighting {
+    ///The graphic arts weighting, with `kL = 1`, `K1 = 0.045` and `K2 = 0.015`.
+    GraphicArts,
+    ///The textiles weighting, with `kL = 2`, `K1 = 0.048` and `K2 = 0.014`.
+    Textiles,
+}
+
+impl Cie94Weighting {
+    ///The parameters `kL`, `K1` and `K2`.
+    fn parameters<T: Float>(&self) -> (T, T, T) {
+        match *self {
+            Cie94Weighting::GraphicArts => (cast(1.0), cast(0.045), cast(0.015)),
+            Cie94Weighting::Textiles => (cast(2.0), cast(0.048), cast(0.014)),
+        }
+    }
+}
+
+///Perceptual color difference between two colors.
+///
+///This is implemented for every color that can be converted into `Lab`, and
+///the other color can be of any type with the same white point.
+pub trait ColorDifference<Wp = ::white_point::D65, T = f32>: IntoColor<Wp, T>
+where
+    T: Component + Float,
+    Wp: WhitePoint,
+{
+    ///The CIE76 color difference, ΔE\*ab, which is the euclidean distance in
+    ///`Lab`.
+    fn delta_e_cie76<C: IntoColor<Wp, T>>(self, other: C) -> T {
+        cie76(&self.into_lab(), &other.into_lab())
+    }
+
+    ///The CIE94 color difference, with `self` as the reference color.
+    fn delta_e_cie94<C: IntoColor<Wp, T>>(self, other: C, weighting: Cie94Weighting) -> T {
+        cie94(&self.into_lab(), &other.into_lab(), weighting)
+    }
+
+    ///The CIEDE2000 color difference, with the parametric factors `kL`, `kC`
+    ///and `kH` set to `1`.
+    fn delta_e_ciede2000<C: IntoColor<Wp, T>>(self, other: C) -> T {
+        ciede2000(&self.into_lab(), &other.into_lab(), T::one(), T::one(), T::one())
+    }
+
+    ///The CIEDE2000 color difference, with the parametric factors `kL`, `kC`
+    ///and `kH`.
+    fn delta_e_ciede2000_with<C: IntoColor<Wp, T>>(self, other: C, kl: T, kc: T, kh: T) -> T {
+        ciede2000(&self.into_lab(), &other.into_lab(), kl, kc, kh)
+    }
+
+    ///The CMC l:c color difference, with `self` as the reference color. The
+    ///common choices are 2:1 for acceptability and 1:1 for perceptibility.
+    fn delta_e_cmc<C: IntoColor<Wp, T>>(self, other: C, l: T, c: T) -> T {
+        cmc(&self.into_lab(), &other.into_lab(), l, c)
+    }
+}
+
+impl<C, Wp, T> ColorDifference<Wp, T> for C
+where
+    C: IntoColor<Wp, T>,
+    T: Component + Float,
+    Wp: WhitePoint,
+{
+}
+
+///The CIE76 color difference, ΔE\*ab.
+pub fn cie76<Wp: WhitePoint, T: Component + Float>(a: &Lab<Wp, T>, b: &Lab<Wp, T>) -> T {
+    let dl = a.l - b.l;
+    let da = a.a - b.a;
+    let db = a.b - b.b;
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+///The CIE94 color difference, with `reference` as the reference color.
+pub fn cie94<Wp: WhitePoint, T: Component + Float>(
+    reference: &Lab<Wp, T>,
+    sample: &Lab<Wp, T>,
+    weighting: Cie94Weighting,
+) -> T {
+    let (kl, k1, k2) = weighting.parameters::<T>();
+
+    let c1 = chroma(reference);
+    let c2 = chroma(sample);
+
+    let dl = reference.l - sample.l;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    let dh_squared = (da * da + db * db - dc * dc).max(T::zero());
+
+    let sc = T::one() + k1 * c1;
+    let sh = T::one() + k2 * c1;
+
+    let l_term = dl / kl;
+    let c_term = dc / sc;
+    (l_term * l_term + c_term * c_term + dh_squared / (sh * sh)).sqrt()
+}
+
+///The CIEDE2000 color difference, with the parametric factors `kl`, `kc` and
+///`kh`.
+///
+///This follows the implementation notes by Sharma, Wu and Dalal, and is
+///symmetric.
+pub fn ciede2000<Wp: WhitePoint, T: Component + Float>(
+    a: &Lab<Wp, T>,
+    b: &Lab<Wp, T>,
+    kl: T,
+    kc: T,
+    kh: T,
+) -> T {
+    let two: T = cast(2.0);
+    let pow25_7: T = cast(6103515625.0);
+
+    let mean_c = (chroma(a) + chroma(b)) / two;
+    let mean_c_7 = mean_c.powi(7);
+    let g = cast::<T, _>(0.5) * (T::one() - (mean_c_7 / (mean_c_7 + pow25_7)).sqrt());
+
+    let a1 = (T::one() + g) * a.a;
+    let a2 = (T::one() + g) * b.a;
+    let c1 = (a1 * a1 + a.b * a.b).sqrt();
+    let c2 = (a2 * a2 + b.b * b.b).sqrt();
+    let h1 = hue_degrees(a1, a.b);
+    let h2 = hue_degrees(a2, b.b);
+
+    let delta_l = b.l - a.l;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == T::zero() {
+        T::zero()
+    } else {
+        let difference = h2 - h1;
+        if difference > cast(180.0) {
+            difference - cast(360.0)
+        } else if difference < cast(-180.0) {
+            difference + cast(360.0)
+        } else {
+            difference
+        }
+    };
+    let delta_big_h = two * (c1 * c2).sqrt() * (delta_h / two).to_radians().sin();
+
+    let mean_l = (a.l + b.l) / two;
+    let mean_c = (c1 + c2) / two;
+    let mean_h = if c1 * c2 == T::zero() {
+        h1 + h2
+    } else if (h1 - h2).abs() <= cast(180.0) {
+        (h1 + h2) / two
+    } else if h1 + h2 < cast(360.0) {
+        (h1 + h2 + cast(360.0)) / two
+    } else {
+        (h1 + h2 - cast(360.0)) / two
+    };
+
+    let t = T::one() - cast::<T, _>(0.17) * (mean_h - cast(30.0)).to_radians().cos()
+        + cast::<T, _>(0.24) * (two * mean_h).to_radians().cos()
+        + cast::<T, _>(0.32) * (cast::<T, _>(3.0) * mean_h + cast(6.0)).to_radians().cos()
+        - cast::<T, _>(0.20) * (cast::<T, _>(4.0) * mean_h - cast(63.0)).to_radians().cos();
+
+    let delta_theta = cast::<T, _>(30.0) * (-((mean_h - cast(275.0)) / cast(25.0)).powi(2)).exp();
+    let mean_c_7 = mean_c.powi(7);
+    let rc = two * (mean_c_7 / (mean_c_7 + pow25_7)).sqrt();
+
+    let l_offset = (mean_l - cast(50.0)).powi(2);
+    let sl = T::one() + cast::<T, _>(0.015) * l_offset / (cast::<T, _>(20.0) + l_offset).sqrt();
+    let sc = T::one() + cast::<T, _>(0.045) * mean_c;
+    let sh = T::one() + cast::<T, _>(0.015) * mean_c * t;
+    let rt = -(two * delta_theta).to_radians().sin() * rc;
+
+    let l_term = delta_l / (kl * sl);
+    let c_term = delta_c / (kc * sc);
+    let h_term = delta_big_h / (kh * sh);
+
+    (l_term * l_term + c_term * c_term + h_term * h_term + rt * c_term * h_term).sqrt()
+}
+
+///The CMC l:c color difference, with `reference` as the reference color.
+pub fn cmc<Wp: WhitePoint, T: Component + Float>(
+    reference: &Lab<Wp, T>,
+    sample: &Lab<Wp, T>,
+    l: T,
+    c: T,
+) -> T {
+    let c1 = chroma(reference);
+    let c2 = chroma(sample);
+    let h1 = hue_degrees(reference.a, reference.b);
+
+    let dl = reference.l - sample.l;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    let dh_squared = (da * da + db * db - dc * dc).max(T::zero());
+
+    let sl = if reference.l < cast(16.0) {
+        cast(0.511)
+    } else {
+        cast::<T, _>(0.040975) * reference.l / (T::one() + cast::<T, _>(0.01765) * reference.l)
+    };
+    let sc = cast::<T, _>(0.0638) * c1 / (T::one() + cast::<T, _>(0.0131) * c1) + cast(0.638);
+
+    let c1_4 = c1.powi(4);
+    let f = (c1_4 / (c1_4 + cast(1900.0))).sqrt();
+    let t = if h1 >= cast(164.0) && h1 <= cast(345.0) {
+        cast::<T, _>(0.56) + (cast::<T, _>(0.2) * (h1 + cast(168.0)).to_radians().cos()).abs()
+    } else {
+        cast::<T, _>(0.36) + (cast::<T, _>(0.4) * (h1 + cast(35.0)).to_radians().cos()).abs()
+    };
+    let sh = sc * (f * t + T::one() - f);
+
+    let l_term = dl / (l * sl);
+    let c_term = dc / (c * sc);
+    (l_term * l_term + c_term * c_term + dh_squared / (sh * sh)).sqrt()
+}
+
+fn chroma<Wp: WhitePoint, T: Component + Float>(color: &Lab<Wp, T>) -> T {
+    (color.a * color.a + color.b * color.b).sqrt()
+}
+
+///The hue angle in degrees, from 0 to 360.
+fn hue_degrees<T: Float>(a: T, b: T) -> T {
+    if a == T::zero() && b == T::zero() {
+        return T::zero();
+    }
+
+    let hue = b.atan2(a).to_degrees();
+    if hue < T::zero() {
+        hue + cast(360.0)
+    } else {
+        hue
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {IntoColor, Lab, LinSrgb, Lch};
+    use super::{cie76, cie94, ciede2000, cmc, Cie94Weighting, ColorDifference};
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const SHARMA: [[f64; 7]; 34] = [
+        [50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425],
+        [50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615],
+        [50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412],
+        [50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000],
+        [50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000],
+        [50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000],
+        [50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669],
+        [50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461],
+        [50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065],
+        [50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492],
+        [50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977],
+        [50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030],
+        [50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000],
+        [60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644],
+        [63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630],
+        [61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731],
+        [35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645],
+        [22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373],
+        [36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146],
+        [90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441],
+        [90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381],
+        [6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377],
+        [2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082],
+    ];
+
+    #[test]
+    fn ciede2000_sharma() {
+        for row in SHARMA.iter() {
+            let a = Lab::new(row[0], row[1], row[2]);
+            let b = Lab::new(row[3], row[4], row[5]);
+
+            assert_relative_eq!(ciede2000(&a, &b, 1.0, 1.0, 1.0), row[6], epsilon = 0.0001);
+            assert_relative_eq!(ciede2000(&b, &a, 1.0, 1.0, 1.0), row[6], epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn ciede2000_parametric_factors() {
+        let a = Lab::new(50.0f64, 0.0, 0.0);
+        let b = Lab::new(60.0f64, 0.0, 0.0);
+
+        let unweighted = a.delta_e_ciede2000(b);
+        assert_relative_eq!(a.delta_e_ciede2000_with(b, 2.0, 1.0, 1.0), unweighted / 2.0);
+    }
+
+    #[test]
+    fn cie76_distance() {
+        let a = Lab::new(50.0f64, 10.0, -10.0);
+        let b = Lab::new(53.0f64, 14.0, -10.0);
+
+        assert_relative_eq!(cie76(&a, &b), 5.0);
+    }
+
+    #[test]
+    fn cie94_weightings() {
+        let a = Lab::new(50.0f64, 20.0, 30.0);
+        let lighter = Lab::new(54.0f64, 20.0, 30.0);
+
+        //Lightness differences are only scaled by kL.
+        assert_relative_eq!(cie94(&a, &lighter, Cie94Weighting::GraphicArts), 4.0);
+        assert_relative_eq!(cie94(&a, &lighter, Cie94Weighting::Textiles), 2.0);
+
+        //The chroma weighting depends on the reference.
+        let b = Lab::new(50.0f64, 40.0, 30.0);
+        let forward = cie94(&a, &b, Cie94Weighting::GraphicArts);
+        let backward = cie94(&b, &a, Cie94Weighting::GraphicArts);
+        assert!(forward > backward);
+        assert!(forward < cie76(&a, &b));
+    }
+
+    #[test]
+    fn cmc_lightness() {
+        let a = Lab::new(50.0f64, 0.0, 0.0);
+        let b = Lab::new(52.0f64, 0.0, 0.0);
+        let sl = 0.040975 * 50.0 / (1.0 + 0.01765 * 50.0);
+
+        assert_relative_eq!(cmc(&a, &b, 1.0, 1.0), 2.0 / sl, epsilon = 0.000001);
+        assert_relative_eq!(cmc(&a, &b, 2.0, 1.0), 1.0 / sl, epsilon = 0.000001);
+
+        let dark = Lab::new(10.0f64, 0.0, 0.0);
+        let darker = Lab::new(9.0f64, 0.0, 0.0);
+        assert_relative_eq!(cmc(&dark, &darker, 1.0, 1.0), 1.0 / 0.511, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn any_color() {
+        let lab = Lab::new(60.0f64, 30.0, -20.0);
+        let lch: Lch<_, f64> = lab.into();
+        let other = Lab::new(55.0f64, 25.0, -10.0);
+
+        assert_relative_eq!(
+            lch.delta_e_ciede2000(other),
+            lab.delta_e_ciede2000(other),
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            lch.delta_e_cmc(other, 2.0, 1.0),
+            lab.delta_e_cmc(other, 2.0, 1.0),
+            epsilon = 0.000001
+        );
+
+        let rgb = LinSrgb::new(0.5f64, 0.2, 0.1);
+        assert_relative_eq!(rgb.delta_e_cie76(rgb.into_lab()), 0.0, epsilon = 0.000001);
+    }
+}
@@ -0,0 +1,238 @@
+//!The APCA perceptual contrast algorithm, as drafted for WCAG 3.
+//!
+//!APCA gives a lightness contrast value, `Lc`, from about `-108` to `106`.
+//!Unlike the WCAG 2.x contrast ratio, the order of the colors matters. Dark
+//!text on a light background gives a positive value and light text on a dark
+//!background gives a negative value.
+//!
+//!This implements the APCA-W3 0.0.98G-4g constants.
+//!
+//!```
+//!use palette::Srgb;
+//!use palette::contrast::apca;
+//!
+//!let text = Srgb::new(0x88 as f64 / 255.0, 0x88 as f64 / 255.0, 0x88 as f64 / 255.0);
+//!let background = Srgb::new(1.0, 1.0, 1.0);
+//!
+//!let lc = apca::apca_contrast(text, background);
+//!assert!((lc - 63.056469930209424).abs() < 0.000001);
+//!assert_eq!(apca::min_font_size(lc, 400), Some(24.0));
+//!```
+
+use num_traits::Float;
+
+use {cast, Component, Srgb};
+
+const MAIN_TRC: f64 = 2.4;
+const RED: f64 = 0.2126729;
+const GREEN: f64 = 0.7151522;
+const BLUE: f64 = 0.0721750;
+
+const NORMAL_BACKGROUND: f64 = 0.56;
+const NORMAL_TEXT: f64 = 0.57;
+const REVERSE_TEXT: f64 = 0.62;
+const REVERSE_BACKGROUND: f64 = 0.65;
+
+const BLACK_THRESHOLD: f64 = 0.022;
+const BLACK_CLAMP: f64 = 1.414;
+const SCALE: f64 = 1.14;
+const OFFSET: f64 = 0.027;
+const DELTA_Y_MIN: f64 = 0.0005;
+const LOW_CLIP: f64 = 0.1;
+
+///The APCA lightness contrast, `Lc`, of `text` on `background`.
+///
+///The result is positive for dark text on a light background and negative for
+///light text on a dark background. Contrasts below about `Lc 7.3`, where the
+///scaled contrast is below the low clip of `0.1`, are reported as `0.0`.
+pub fn apca_contrast<T: Component + Float>(text: Srgb<T>, background: Srgb<T>) -> T {
+    let text = screen_luminance(text);
+    let background = screen_luminance(background);
+
+    if (background - text).abs() < cast(DELTA_Y_MIN) {
+        return T::zero();
+    }
+
+    let contrast = if background > text {
+        let contrast = (background.powf(cast(NORMAL_BACKGROUND)) - text.powf(cast(NORMAL_TEXT)))
+            * cast(SCALE);
+
+        if contrast < cast(LOW_CLIP) {
+            T::zero()
+        } else {
+            contrast - cast(OFFSET)
+        }
+    } else {
+        let contrast = (background.powf(cast(REVERSE_BACKGROUND)) - text.powf(cast(REVERSE_TEXT)))
+            * cast(SCALE);
+
+        if contrast > cast(-LOW_CLIP) {
+            T::zero()
+        } else {
+            contrast + cast(OFFSET)
+        }
+    };
+
+    contrast * cast(100.0)
+}
+
+///The estimated screen luminance, with the soft clamp of near black colors.
+fn screen_luminance<T: Component + Float>(color: Srgb<T>) -> T {
+    let trc: T = cast(MAIN_TRC);
+    let luminance = cast::<T, _>(RED) * color.red.max(T::zero()).powf(trc)
+        + cast::<T, _>(GREEN) * color.green.max(T::zero()).powf(trc)
+        + cast::<T, _>(BLUE) * color.blue.max(T::zero()).powf(trc);
+
+    if luminance < cast(BLACK_THRESHOLD) {
+        luminance + (cast::<T, _>(BLACK_THRESHOLD) - luminance).powf(cast(BLACK_CLAMP))
+    } else {
+        luminance
+    }
+}
+
+///Marks weights that should only be used for non-text elements.
+const NON_TEXT: f32 = 777.0;
+///Marks weights that should not be used at all.
+const PROHIBITED: f32 = 999.0;
+
+///The minimum font sizes in px for each font weight from 100 to 900, for each
+///`Lc` from 10 to 125 in steps of 5.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const FONT_SIZES: [[f32; 9]; 24] = [
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [777.0, 777.0, 777.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0],
+    [777.0, 777.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0],
+    [777.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0],
+    [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0],
+    [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0],
+    [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0],
+    [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0],
+    [56.0, 38.25, 23.0, 17.25, 15.81, 14.0, 13.0, 16.0, 18.0],
+    [52.0, 34.5, 22.0, 16.5, 15.625, 13.5, 13.0, 16.0, 18.0],
+    [48.0, 32.0, 21.0, 16.0, 15.5, 13.0, 12.0, 16.0, 18.0],
+    [45.0, 28.0, 19.5, 15.5, 15.0, 12.0, 12.0, 16.0, 18.0],
+    [42.0, 26.5, 18.5, 15.0, 14.5, 12.0, 12.0, 16.0, 18.0],
+    [39.0, 25.0, 18.0, 14.0, 14.0, 12.0, 12.0, 16.0, 18.0],
+    [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 12.0, 16.0, 18.0],
+    [34.5, 22.5, 17.25, 12.5, 11.875, 12.0, 12.0, 16.0, 18.0],
+    [33.0, 21.0, 16.0, 12.0, 11.0, 12.0, 12.0, 16.0, 18.0],
+    [32.0, 20.0, 15.0, 12.0, 11.0, 12.0, 12.0, 16.0, 18.0],
+];
+
+///The minimum font size in px for text with the contrast `lc` and the font
+///weight `weight`, from the APCA font lookup table.
+///
+///The sign of `lc` is ignored, and it's rounded down to the nearest step of
+///`5`. The weight is rounded to the nearest hundred, from 100 to 900. Returns
+///`None` if the contrast is too low for text with that weight.
+pub fn min_font_size<T: Float>(lc: T, weight: u16) -> Option<f32> {
+    let size = lookup(lc, weight_column(weight));
+
+    if size >= NON_TEXT {
+        None
+    } else {
+        Some(size)
+    }
+}
+
+///The lightest font weight, from 100 to 900, that can be used for text with the
+///contrast `lc` and the font size `size`, in px.
+///
+///Returns `None` if no weight has a small enough minimum size.
+pub fn min_font_weight<T: Float>(lc: T, size: f32) -> Option<u16> {
+    (0..9)
+        .find(|&column| {
+            let min_size = lookup(lc, column);
+            min_size < NON_TEXT && min_size <= size
+        })
+        .map(|column| (column as u16 + 1) * 100)
+}
+
+///Check if the contrast `lc` is only enough for non-text elements, such as
+///borders and icons, and not for text of any size or weight.
+pub fn is_non_text_only<T: Float>(lc: T) -> bool {
+    let size = lookup(lc, 8);
+    size >= NON_TEXT && size < PROHIBITED
+}
+
+fn weight_column(weight: u16) -> usize {
+    let weight = weight.max(100).min(900);
+    ((weight + 50) / 100 - 1) as usize
+}
+
+fn lookup<T: Float>(lc: T, column: usize) -> f32 {
+    let lc = lc.abs().to_f32().unwrap_or(0.0);
+
+    if lc < 10.0 {
+        return PROHIBITED;
+    }
+
+    let row = ((lc - 10.0) / 5.0).floor() as usize;
+    FONT_SIZES[row.min(FONT_SIZES.len() - 1)][column]
+}
+
+#[cfg(test)]
+mod test {
+    use Srgb;
+    use super::{apca_contrast, is_non_text_only, min_font_size, min_font_weight};
+
+    fn hex(value: u32) -> Srgb<f64> {
+        Srgb::new(
+            ((value >> 16) & 0xff) as f64 / 255.0,
+            ((value >> 8) & 0xff) as f64 / 255.0,
+            (value & 0xff) as f64 / 255.0,
+        )
+    }
+
+    #[test]
+    fn reference_vectors() {
+        let vectors = [
+            (0x888888, 0xffffff, 63.056469930209424),
+            (0xffffff, 0x888888, -68.54146436644962),
+            (0x000000, 0xaaaaaa, 58.146262578561334),
+            (0xaaaaaa, 0x000000, -56.24113336839742),
+            (0x112233, 0xddeeff, 91.66830811481631),
+            (0xddeeff, 0x112233, -93.06770049484275),
+            (0x112233, 0x444444, 8.32326136957393),
+            (0x444444, 0x112233, -7.526878460278154),
+        ];
+
+        for &(text, background, expected) in &vectors {
+            assert_relative_eq!(
+                apca_contrast(hex(text), hex(background)),
+                expected,
+                epsilon = 0.000001
+            );
+        }
+    }
+
+    #[test]
+    fn same_color() {
+        assert_eq!(apca_contrast(hex(0x777777), hex(0x777777)), 0.0);
+    }
+
+    #[test]
+    fn font_lookup() {
+        assert_eq!(min_font_size(90.0, 400), Some(16.0));
+        assert_eq!(min_font_size(-92.0, 400), Some(16.0));
+        assert_eq!(min_font_size(75.0, 300), Some(24.0));
+        assert_eq!(min_font_size(75.0, 349), Some(24.0));
+        assert_eq!(min_font_size(20.0, 900), None);
+        assert_eq!(min_font_size(5.0, 400), None);
+
+        assert_eq!(min_font_weight(60.0, 24.0), Some(400));
+        assert_eq!(min_font_weight(60.0, 10.0), None);
+        assert_eq!(min_font_weight(15.0, 1000.0), None);
+        assert_eq!(min_font_weight(5.0, 1000.0), None);
+
+        assert!(is_non_text_only(15.0));
+        assert!(!is_non_text_only(5.0));
+        assert!(!is_non_text_only(45.0));
+    }
+}
@@ -0,0 +1,254 @@
+//!WCAG relative luminance and contrast ratio.
+//!
+//!The contrast ratio between two colors is `(L1 + 0.05) / (L2 + 0.05)`, where
+//!`L1` is the relative luminance of the lighter color and `L2` is the relative
+//!luminance of the darker color. WCAG 2.x requires at least `4.5` for normal
+//!text and `3.0` for large text at level AA.
+//!
+//!```
+//!use palette::Srgb;
+//!use palette::contrast::{self, RelativeContrast};
+//!
+//!let foreground = Srgb::new(0.5, 0.5, 0.5);
+//!let background = Srgb::new(1.0, 1.0, 1.0);
+//!
+//!if foreground.contrast_ratio(&background) < 4.5 {
+//!    let fixed = contrast::fix_contrast(foreground, &background, 4.5).unwrap();
+//!    assert!(fixed.contrast_ratio(&background) >= 4.5);
+//!}
+//!```
+//!
+//!The [`apca`](apca/index.html) module has the APCA contrast algorithm from
+//!the WCAG 3 draft.
+
+pub mod apca;
+
+use num_traits::Float;
+
+use {cast, Component, IntoColor, LabHue, Lch, Limited, Xyz};
+use rgb::{Rgb, RgbSpace, RgbStandard};
+use luma::{Luma, LumaStandard};
+use encoding::Linear;
+use gamut;
+use white_point::WhitePoint;
+
+pub use self::apca::apca_contrast;
+
+///Colors with a WCAG relative luminance.
+pub trait RelativeContrast {
+    ///The type of the luminance and contrast ratio.
+    type Scalar: Float;
+
+    ///The relative luminance of the color, from `0.0` for black to `1.0` for
+    ///white.
+    fn relative_luminance(&self) -> Self::Scalar;
+
+    ///The contrast ratio between this color and `other`, from `1.0` to `21.0`.
+    ///The order of the colors doesn't matter.
+    fn contrast_ratio<C>(&self, other: &C) -> Self::Scalar
+    where
+        C: RelativeContrast<Scalar = Self::Scalar>,
+    {
+        contrast_ratio(self, other)
+    }
+}
+
+impl<S, T> RelativeContrast for Rgb<S, T>
+where
+    S: RgbStandard,
+    T: Component + Float,
+{
+    type Scalar = T;
+
+    fn relative_luminance(&self) -> T {
+        let xyz: Xyz<<S::Space as RgbSpace>::WhitePoint, T> = self.into_linear().into();
+        xyz.y
+    }
+}
+
+impl<S, T> RelativeContrast for Luma<S, T>
+where
+    S: LumaStandard,
+    T: Component + Float,
+{
+    type Scalar = T;
+
+    fn relative_luminance(&self) -> T {
+        self.into_linear().luma
+    }
+}
+
+///The contrast ratio between two colors, from `1.0` to `21.0`. The order of
+///the colors doesn't matter.
+pub fn contrast_ratio<A, B>(a: &A, b: &B) -> A::Scalar
+where
+    A: RelativeContrast + ?Sized,
+    B: RelativeContrast<Scalar = A::Scalar> + ?Sized,
+{
+    ratio(a.relative_luminance(), b.relative_luminance())
+}
+
+fn ratio<T: Float>(a: T, b: T) -> T {
+    let offset: T = cast(0.05);
+    let (light, dark) = if a > b { (a, b) } else { (b, a) };
+    (light + offset) / (dark + offset)
+}
+
+///Find the color closest to `foreground` that has at least the contrast ratio
+///`target` against `background`.
+///
+///Only the `Lch` lightness of the foreground is changed, except where the
+///chroma has to be reduced to stay within the RGB gamut. Both lighter and
+///darker colors are considered, and the one with the smallest change in
+///lightness is returned. The foreground is returned as it is if it already has
+///enough contrast, and `None` is returned if neither black nor white has
+///enough contrast.
+pub fn fix_contrast<S, B, T>(foreground: Rgb<S, T>, background: &B, target: T) -> Option<Rgb<S, T>>
+where
+    S: RgbStandard,
+    B: RelativeContrast<Scalar = T> + ?Sized,
+    T: Component + Float,
+{
+    let background = background.relative_luminance();
+    if ratio(foreground.relative_luminance(), background) >= target {
+        return Some(foreground);
+    }
+
+    let lch: Lch<<S::Space as RgbSpace>::WhitePoint, T> = foreground.into_lch();
+    let max_lightness: T = cast(100.0);
+
+    let lighter = search_lightness::<_, S::Space, _>(&lch, background, target, max_lightness);
+    let darker = search_lightness::<_, S::Space, _>(&lch, background, target, T::zero());
+
+    let lightness = match (lighter, darker) {
+        (Some(lighter), Some(darker)) => {
+            if lighter - lch.l <= lch.l - darker {
+                lighter
+            } else {
+                darker
+            }
+        }
+        (Some(lightness), None) | (None, Some(lightness)) => lightness,
+        (None, None) => return None,
+    };
+
+    Some(Rgb::from_linear(in_gamut(lightness, lch.chroma, lch.hue)))
+}
+
+///Search for the lightness closest to the start color, between its lightness
+///and `limit`, that has enough contrast.
+fn search_lightness<Wp, S, T>(start: &Lch<Wp, T>, background: T, target: T, limit: T) -> Option<T>
+where
+    Wp: WhitePoint,
+    S: RgbSpace<WhitePoint = Wp>,
+    T: Component + Float,
+{
+    let contrast = |lightness: T| {
+        let rgb: Rgb<Linear<S>, T> = in_gamut(lightness, start.chroma, start.hue);
+        ratio(rgb.relative_luminance(), background)
+    };
+
+    if contrast(limit) < target {
+        return None;
+    }
+
+    //The contrast grows monotonically towards the limit.
+    let (mut near, mut far) = (start.l, limit);
+    for _ in 0..32 {
+        let middle = (near + far) / cast(2.0);
+        if contrast(middle) >= target {
+            far = middle;
+        } else {
+            near = middle;
+        }
+    }
+
+    Some(far)
+}
+
+///The color with the lightness and hue, and as much of the chroma as fits in
+///the RGB gamut.
+fn in_gamut<S, Wp, T>(lightness: T, chroma: T, hue: LabHue<T>) -> Rgb<Linear<S>, T>
+where
+    S: RgbSpace<WhitePoint = Wp>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let rgb: Rgb<Linear<S>, T> = Lch::with_wp(lightness, chroma, hue).into_rgb();
+    if gamut::fits(&rgb) {
+        return rgb;
+    }
+
+    let chroma = gamut::max_chroma::<S, Wp, T>(lightness, hue).min(chroma);
+    Lch::with_wp(lightness, chroma, hue).into_rgb().clamp()
+}
+
+#[cfg(test)]
+mod test {
+    use Srgb;
+    use luma::SrgbLuma;
+    use super::{contrast_ratio, fix_contrast, RelativeContrast};
+
+    #[test]
+    fn relative_luminance() {
+        let color = Srgb::new(0.8f64, 0.4, 0.2);
+        let linear = color.into_linear();
+        let expected = 0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue;
+
+        assert_relative_eq!(color.relative_luminance(), expected, epsilon = 0.0001);
+        assert_relative_eq!(SrgbLuma::new(0.5f64).relative_luminance(), 0.2140411, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn contrast_ratios() {
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+
+        assert_relative_eq!(contrast_ratio(&black, &white), 21.0, epsilon = 0.0001);
+        assert_relative_eq!(contrast_ratio(&white, &black), 21.0, epsilon = 0.0001);
+        assert_relative_eq!(white.contrast_ratio(&white), 1.0);
+
+        //#767676 is the lightest gray that passes AA on white.
+        let gray = Srgb::new(118.0f64 / 255.0, 118.0 / 255.0, 118.0 / 255.0);
+        assert_relative_eq!(gray.contrast_ratio(&white), 4.54, epsilon = 0.01);
+        assert_relative_eq!(SrgbLuma::new(118.0f64 / 255.0).contrast_ratio(&white), 4.54, epsilon = 0.01);
+    }
+
+    #[test]
+    fn fix_keeps_good_contrast() {
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+
+        assert_eq!(fix_contrast(black, &white, 4.5), Some(black));
+    }
+
+    #[test]
+    fn fix_darkens_on_light_background() {
+        let foreground = Srgb::new(0.3f64, 0.6, 0.9);
+        let background = Srgb::new(0.95f64, 0.95, 0.95);
+
+        let fixed = fix_contrast(foreground, &background, 4.5).unwrap();
+        let ratio = fixed.contrast_ratio(&background);
+        assert!(ratio >= 4.5);
+        assert!(ratio < 4.51);
+        assert!(fixed.relative_luminance() < foreground.relative_luminance());
+    }
+
+    #[test]
+    fn fix_lightens_on_dark_background() {
+        let foreground = Srgb::new(0.4f64, 0.1, 0.1);
+        let background = Srgb::new(0.1f64, 0.1, 0.1);
+
+        let fixed = fix_contrast(foreground, &background, 7.0).unwrap();
+        assert!(fixed.contrast_ratio(&background) >= 7.0);
+        assert!(fixed.relative_luminance() > foreground.relative_luminance());
+    }
+
+    #[test]
+    fn fix_impossible() {
+        let foreground = Srgb::new(0.5f64, 0.5, 0.5);
+        let background = Srgb::new(0.5f64, 0.5, 0.5);
+
+        assert_eq!(fix_contrast(foreground, &background, 7.0), None);
+    }
+}
@@ -0,0 +1,359 @@
+//! Parametric and sampled tone curves, as used in ICC profiles.
+//!
+//! These curves describe the tone response of a device with values instead of
+//! types, so they are used through the
+//! [`TransferCurve`](../trait.TransferCurve.html) trait.
+//!
+//! ```
+//! use palette::LinSrgb;
+//! use palette::encoding::{ParametricCurve, TransferCurve};
+//!
+//! // The sRGB curve, as an ICC parametricCurveType of type 3.
+//! let params = [2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045];
+//! let curve = ParametricCurve::from_icc(3, &params).unwrap();
+//!
+//! let linear: LinSrgb = curve.rgb_into_linear([0.8, 0.5, 0.2]);
+//! ```
+
+use num_traits::Float;
+
+use {clamp, Component};
+use encoding::TransferCurve;
+
+/// An ICC `parametricCurveType` tone curve.
+///
+/// All five function types are represented by the most general one, type 4,
+/// where the linear value _Y_ is computed from the encoded value _X_ as
+///
+/// * _Y = (aX + b)<sup>g</sup> + e_ when _X ≥ d_,
+/// * _Y = cX + f_ when _X < d_.
+///
+/// The curve is inverted analytically.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ParametricCurve<T: Float = f32> {
+    /// The exponent `g`.
+    pub g: T,
+    /// The scale `a` of the power segment.
+    pub a: T,
+    /// The offset `b` of the power segment.
+    pub b: T,
+    /// The slope `c` of the linear segment.
+    pub c: T,
+    /// The threshold `d` between the segments.
+    pub d: T,
+    /// The offset `e` of the power segment.
+    pub e: T,
+    /// The offset `f` of the linear segment.
+    pub f: T,
+}
+
+impl<T: Float> ParametricCurve<T> {
+    /// Function type 0: _Y = X<sup>g</sup>_.
+    pub fn gamma(g: T) -> ParametricCurve<T> {
+        ParametricCurve::full(g, T::one(), T::zero(), T::zero(), T::zero(), T::zero(), T::zero())
+    }
+
+    /// Function type 1 (CIE 122-1966): _Y = (aX + b)<sup>g</sup>_ when
+    /// _X ≥ -b/a_, otherwise _Y = 0_.
+    pub fn cie122(g: T, a: T, b: T) -> ParametricCurve<T> {
+        ParametricCurve::full(g, a, b, T::zero(), -b / a, T::zero(), T::zero())
+    }
+
+    /// Function type 2 (IEC 61966-3): _Y = (aX + b)<sup>g</sup> + c_ when
+    /// _X ≥ -b/a_, otherwise _Y = c_.
+    pub fn iec61966_3(g: T, a: T, b: T, c: T) -> ParametricCurve<T> {
+        ParametricCurve::full(g, a, b, T::zero(), -b / a, c, c)
+    }
+
+    /// Function type 3 (IEC 61966-2.1, sRGB): _Y = (aX + b)<sup>g</sup>_ when
+    /// _X ≥ d_, otherwise _Y = cX_.
+    pub fn srgb_like(g: T, a: T, b: T, c: T, d: T) -> ParametricCurve<T> {
+        ParametricCurve::full(g, a, b, c, d, T::zero(), T::zero())
+    }
+
+    /// Function type 4: _Y = (aX + b)<sup>g</sup> + e_ when _X ≥ d_, otherwise
+    /// _Y = cX + f_.
+    pub fn full(g: T, a: T, b: T, c: T, d: T, e: T, f: T) -> ParametricCurve<T> {
+        ParametricCurve {
+            g: g,
+            a: a,
+            b: b,
+            c: c,
+            d: d,
+            e: e,
+            f: f,
+        }
+    }
+
+    /// Create a curve from an ICC function type and its parameters, in the
+    /// order they are stored in the profile.
+    ///
+    /// Returns `None` if the function type is unknown or if there are too few
+    /// parameters.
+    pub fn from_icc(function_type: u16, params: &[T]) -> Option<ParametricCurve<T>> {
+        let needed = match function_type {
+            0 => 1,
+            1 => 3,
+            2 => 4,
+            3 => 5,
+            4 => 7,
+            _ => return None,
+        };
+
+        if params.len() < needed {
+            return None;
+        }
+
+        let p = params;
+        Some(match function_type {
+            0 => ParametricCurve::gamma(p[0]),
+            1 => ParametricCurve::cie122(p[0], p[1], p[2]),
+            2 => ParametricCurve::iec61966_3(p[0], p[1], p[2], p[3]),
+            3 => ParametricCurve::srgb_like(p[0], p[1], p[2], p[3], p[4]),
+            _ => ParametricCurve::full(p[0], p[1], p[2], p[3], p[4], p[5], p[6]),
+        })
+    }
+}
+
+impl<T: Component + Float> TransferCurve<T> for ParametricCurve<T> {
+    fn decode(&self, x: T) -> T {
+        if x >= self.d {
+            (self.a * x + self.b).max(T::zero()).powf(self.g) + self.e
+        } else {
+            self.c * x + self.f
+        }
+    }
+
+    fn encode(&self, x: T) -> T {
+        let threshold = (self.a * self.d + self.b).max(T::zero()).powf(self.g) + self.e;
+
+        if x >= threshold {
+            ((x - self.e).max(T::zero()).powf(T::one() / self.g) - self.b) / self.a
+        } else if self.c != T::zero() {
+            (x - self.f) / self.c
+        } else {
+            self.d
+        }
+    }
+}
+
+/// A tone curve that is sampled at evenly spaced encoded values, like an ICC
+/// `curveType` with more than one entry.
+///
+/// The first sample is the linear value for the encoded value `0.0` and the
+/// last sample is the linear value for `1.0`. Values in between are linearly
+/// interpolated and values outside are clamped. The curve is inverted
+/// numerically, which requires the samples to be non-decreasing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SampledCurve<T: Float = f32> {
+    samples: Vec<T>,
+}
+
+impl<T: Float> SampledCurve<T> {
+    /// Create a curve from its samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two samples.
+    pub fn new(samples: Vec<T>) -> SampledCurve<T> {
+        assert!(samples.len() >= 2, "a sampled curve needs at least two samples");
+        SampledCurve { samples: samples }
+    }
+
+    /// Create a curve by sampling a function at `count` evenly spaced encoded
+    /// values from `0.0` to `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is less than two.
+    pub fn from_fn<F: FnMut(T) -> T>(count: usize, mut curve: F) -> SampledCurve<T> {
+        assert!(count >= 2, "a sampled curve needs at least two samples");
+        let last = T::from(count - 1).unwrap();
+        SampledCurve::new(
+            (0..count)
+                .map(|i| curve(T::from(i).unwrap() / last))
+                .collect(),
+        )
+    }
+
+    /// The samples of the curve.
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+}
+
+impl<T: Component + Float> TransferCurve<T> for SampledCurve<T> {
+    fn decode(&self, x: T) -> T {
+        if x.is_nan() {
+            return x;
+        }
+
+        let last = self.samples.len() - 1;
+        let position = clamp(x, T::zero(), T::one()) * T::from(last).unwrap();
+        let index = position.floor().to_usize().unwrap().min(last - 1);
+        let fraction = position - T::from(index).unwrap();
+
+        let low = self.samples[index];
+        let high = self.samples[index + 1];
+        low + (high - low) * fraction
+    }
+
+    fn encode(&self, x: T) -> T {
+        let last = self.samples.len() - 1;
+
+        if x <= self.samples[0] {
+            return T::zero();
+        }
+        if x >= self.samples[last] {
+            return T::one();
+        }
+
+        //Find the first segment that ends above x.
+        let (mut low, mut high) = (0, last);
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if self.samples[middle] < x {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        let start = self.samples[low];
+        let end = self.samples[high];
+        let fraction = if end > start {
+            (x - start) / (end - start)
+        } else {
+            T::zero()
+        };
+
+        (T::from(low).unwrap() + fraction) / T::from(last).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use encoding::{Srgb, TransferCurve, TransferFn};
+    use super::{ParametricCurve, SampledCurve};
+
+    fn srgb_curve() -> ParametricCurve<f64> {
+        ParametricCurve::srgb_like(2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045)
+    }
+
+    #[test]
+    fn parametric_srgb() {
+        let curve = srgb_curve();
+
+        for i in 0..101 {
+            let x = i as f64 / 100.0;
+            assert_relative_eq!(curve.decode(x), Srgb::into_linear(x), epsilon = 0.000001);
+            assert_relative_eq!(curve.encode(x), Srgb::from_linear(x), epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn parametric_types() {
+        let params = [2.2, 0.9, 0.1, 0.05, 0.1, 0.02, 0.03];
+
+        assert_eq!(
+            ParametricCurve::from_icc(0, &params),
+            Some(ParametricCurve::gamma(2.2))
+        );
+        assert_eq!(
+            ParametricCurve::from_icc(1, &params),
+            Some(ParametricCurve::cie122(2.2, 0.9, 0.1))
+        );
+        assert_eq!(
+            ParametricCurve::from_icc(2, &params),
+            Some(ParametricCurve::iec61966_3(2.2, 0.9, 0.1, 0.05))
+        );
+        assert_eq!(ParametricCurve::from_icc(3, &params), Some(srgb_like(&params)));
+        assert_eq!(
+            ParametricCurve::from_icc(4, &params),
+            Some(ParametricCurve::full(2.2, 0.9, 0.1, 0.05, 0.1, 0.02, 0.03))
+        );
+        assert_eq!(ParametricCurve::from_icc(5, &params), None);
+        assert_eq!(ParametricCurve::from_icc(4, &params[..6]), None);
+    }
+
+    fn srgb_like(p: &[f64]) -> ParametricCurve<f64> {
+        ParametricCurve::srgb_like(p[0], p[1], p[2], p[3], p[4])
+    }
+
+    #[test]
+    fn parametric_round_trip() {
+        //Make the linear segment meet the power segment at d.
+        let f = (0.9f64 * 0.1 + 0.1).powf(2.2) + 0.02 - 0.2 * 0.1;
+
+        let curves = [
+            ParametricCurve::gamma(2.2),
+            ParametricCurve::cie122(2.2, 0.9, 0.1),
+            ParametricCurve::iec61966_3(2.2, 0.9, 0.1, 0.05),
+            srgb_curve(),
+            ParametricCurve::full(2.2, 0.9, 0.1, 0.2, 0.1, 0.02, f),
+        ];
+
+        for curve in &curves {
+            for i in 0..21 {
+                let x = i as f64 / 20.0;
+                assert_relative_eq!(curve.encode(curve.decode(x)), x, epsilon = 0.000001);
+            }
+        }
+    }
+
+    #[test]
+    fn cie122_below_threshold() {
+        let curve = ParametricCurve::cie122(2.0, 1.0, -0.2);
+
+        assert_relative_eq!(curve.decode(0.1f64), 0.0);
+        assert_relative_eq!(curve.decode(0.7f64), 0.25);
+        assert_relative_eq!(curve.encode(0.0f64), 0.2);
+    }
+
+    #[test]
+    fn sampled_srgb() {
+        let curve = SampledCurve::from_fn(4096, |x: f64| Srgb::into_linear(x));
+
+        for i in 0..101 {
+            let x = i as f64 / 100.0;
+            assert_relative_eq!(curve.decode(x), Srgb::into_linear(x), epsilon = 0.0001);
+            assert_relative_eq!(curve.encode(x), Srgb::from_linear(x), epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn sampled_interpolation() {
+        let curve = SampledCurve::new(vec![0.0, 0.2, 0.6, 1.0]);
+
+        assert_relative_eq!(curve.decode(-1.0f64), 0.0);
+        assert_relative_eq!(curve.decode(0.5f64), 0.4);
+        assert_relative_eq!(curve.decode(2.0f64), 1.0);
+        assert_relative_eq!(curve.encode(0.4f64), 0.5);
+        assert_relative_eq!(curve.encode(0.2f64), 1.0 / 3.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sampled_too_few() {
+        SampledCurve::new(vec![0.5f64]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sampled_from_fn_too_few() {
+        SampledCurve::from_fn(1, |x: f64| x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sampled_from_fn_empty() {
+        SampledCurve::from_fn(0, |x: f64| x);
+    }
+
+    #[test]
+    fn sampled_nan() {
+        let curve = SampledCurve::new(vec![0.0f64, 0.4, 1.0]);
+        assert!(curve.decode(::std::f64::NAN).is_nan());
+        assert!(curve.encode(::std::f64::NAN).is_nan());
+    }
+}
@@ -4,10 +4,10 @@ use std::marker::PhantomData;
 
 use num_traits::Float;
 
-use cast;
+use {cast, Component};
 use rgb::{RgbSpace, RgbStandard};
 use luma::LumaStandard;
-use encoding::TransferFn;
+use encoding::{TransferCurve, TransferFn};
 use white_point::WhitePoint;
 
 /// Gamma encoding.
@@ -15,11 +15,13 @@ use white_point::WhitePoint;
 /// Gamma encoding or gamma correction is used to transform the intensity
 /// values to either match a non-linear display, like CRT, or to prevent
 /// banding among the darker colors. `GammaRgb` represents a gamma corrected
-/// RGB color, where the intensities are encoded using the following power-law
-/// expression: _V<sup> γ</sup>_ (where _V_ is the intensity value an _γ_ is the encoding
-/// gamma).
+/// RGB color, where the encoded values are decoded using the following
+/// power-law expression: _V<sup> γ</sup>_ (where _V_ is the encoded value and
+/// _γ_ is the gamma), and encoded using its inverse: _L<sup> 1/γ</sup>_.
 ///
 /// The gamma value is stored as a simple type that represents an `f32` constant.
+/// Use [`DynamicGamma`](struct.DynamicGamma.html) when the gamma is only known
+/// at runtime.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Gamma<S, N: Number = F2p2>(PhantomData<(S, N)>);
 
@@ -35,17 +37,70 @@ impl<Wp: WhitePoint, N: Number> LumaStandard for Gamma<Wp, N> {
 
 /// The transfer function for gamma encoded colors.
 ///
+/// Converting into linear raises the value to the power of the gamma, so a
+/// gamma of 2.2 turns `0.5` into about `0.218`.
+///
 /// The gamma value is stored as a simple type that represents an `f32` constant.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct GammaFn<N: Number = F2p2>(PhantomData<N>);
 
 impl<N: Number> TransferFn for GammaFn<N> {
     fn into_linear<T: Float>(x: T) -> T {
-        x.powf(T::one() / cast(N::VALUE))
+        x.powf(cast(N::VALUE))
     }
 
     fn from_linear<T: Float>(x: T) -> T {
-        x.powf(cast(N::VALUE))
+        x.powf(T::one() / cast(N::VALUE))
+    }
+}
+
+/// Gamma encoding with a gamma that is chosen at runtime, such as a measured
+/// display gamma.
+///
+/// It follows the same convention as [`GammaFn`](struct.GammaFn.html), but
+/// since the gamma isn't part of the type, the encoded colors can't carry it
+/// in their RGB or luma standard. It's used through the
+/// [`TransferCurve`](../trait.TransferCurve.html) trait instead, with raw
+/// encoded components.
+///
+/// ```
+/// use palette::LinSrgb;
+/// use palette::encoding::{DynamicGamma, TransferCurve};
+///
+/// let gamma = DynamicGamma::new(1.8);
+/// let linear: LinSrgb = gamma.rgb_into_linear([0.5, 0.5, 0.5]);
+/// let encoded: [f32; 3] = gamma.rgb_from_linear(linear);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DynamicGamma<T: Float = f32> {
+    gamma: T,
+}
+
+impl<T: Float> DynamicGamma<T> {
+    /// Create a gamma encoding with the gamma `gamma`.
+    pub fn new(gamma: T) -> DynamicGamma<T> {
+        DynamicGamma { gamma: gamma }
+    }
+
+    /// Create a gamma encoding with the same gamma as the type level constant
+    /// `N`.
+    pub fn from_number<N: Number>() -> DynamicGamma<T> {
+        DynamicGamma::new(cast(N::VALUE))
+    }
+
+    /// The gamma of the encoding.
+    pub fn gamma(&self) -> T {
+        self.gamma
+    }
+}
+
+impl<T: Component + Float> TransferCurve<T> for DynamicGamma<T> {
+    fn decode(&self, x: T) -> T {
+        x.powf(self.gamma)
+    }
+
+    fn encode(&self, x: T) -> T {
+        x.powf(T::one() / self.gamma)
     }
 }
 
@@ -55,6 +110,14 @@ pub trait Number {
     const VALUE: f32;
 }
 
+/// Represents `1.8f32`, the classic Mac gamma.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct F1p8;
+
+impl Number for F1p8 {
+    const VALUE: f32 = 1.8;
+}
+
 /// Represents `2.2f32`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct F2p2;
@@ -62,3 +125,122 @@ pub struct F2p2;
 impl Number for F2p2 {
     const VALUE: f32 = 2.2;
 }
+
+/// Represents `2.4f32`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct F2p4;
+
+impl Number for F2p4 {
+    const VALUE: f32 = 2.4;
+}
+
+/// Represents `2.6f32`, the DCI cinema gamma.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct F2p6;
+
+impl Number for F2p6 {
+    const VALUE: f32 = 2.6;
+}
+
+/// Represents a luminance of `0.0` cd/m².
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Nits0;
+
+impl Number for Nits0 {
+    const VALUE: f32 = 0.0;
+}
+
+/// Represents a luminance of `0.1` cd/m².
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Nits0p1;
+
+impl Number for Nits0p1 {
+    const VALUE: f32 = 0.1;
+}
+
+/// Represents a luminance of `100.0` cd/m².
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Nits100;
+
+impl Number for Nits100 {
+    const VALUE: f32 = 100.0;
+}
+
+/// Represents a luminance of `203.0` cd/m², the HDR reference white from
+/// ITU-R BT.2408.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Nits203;
+
+impl Number for Nits203 {
+    const VALUE: f32 = 203.0;
+}
+
+/// Represents a luminance of `10000.0` cd/m², the peak luminance of PQ.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Nits10000;
+
+impl Number for Nits10000 {
+    const VALUE: f32 = 10000.0;
+}
+
+#[cfg(test)]
+mod test {
+    use {GammaLuma, GammaSrgb, LinLuma, LinSrgb};
+    use rgb::Rgb;
+    use encoding::{Srgb, TransferCurve, TransferFn};
+    use white_point::D65;
+    use super::{DynamicGamma, F1p8, F2p2, F2p6, Gamma, GammaFn};
+
+    #[test]
+    fn into_linear_raises_to_gamma() {
+        assert_relative_eq!(GammaFn::<F2p2>::into_linear(0.5f64), 0.2176376, epsilon = 0.000001);
+        assert_relative_eq!(GammaFn::<F1p8>::into_linear(0.5f64), 0.2871746, epsilon = 0.000001);
+        assert_relative_eq!(GammaFn::<F2p6>::into_linear(0.5f64), 0.1649385, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn from_linear_takes_root() {
+        assert_relative_eq!(GammaFn::<F2p2>::from_linear(0.2176376f64), 0.5, epsilon = 0.000001);
+        assert_relative_eq!(GammaFn::<F2p2>::from_linear(1.0f64), 1.0);
+        assert_relative_eq!(GammaFn::<F2p2>::from_linear(0.0f64), 0.0);
+    }
+
+    #[test]
+    fn gamma_colors() {
+        let rgb = GammaSrgb::new(0.5f64, 0.25, 1.0).into_linear();
+        assert_relative_eq!(
+            rgb,
+            LinSrgb::new(0.5f64.powf(2.2), 0.25f64.powf(2.2), 1.0),
+            epsilon = 0.000001
+        );
+
+        let luma = GammaLuma::new(0.5f64).into_linear();
+        assert_relative_eq!(luma, LinLuma::new(0.5f64.powf(2.2)), epsilon = 0.000001);
+    }
+
+    #[test]
+    fn dynamic_gamma() {
+        let gamma = DynamicGamma::new(2.2f64);
+        let from_number = DynamicGamma::<f64>::from_number::<F2p2>();
+        assert_relative_eq!(gamma.gamma(), from_number.gamma(), epsilon = 0.000001);
+
+        for &x in &[0.0, 0.1, 0.5, 0.9, 1.0] {
+            let linear = gamma.decode(x);
+            assert_relative_eq!(linear, GammaFn::<F2p2>::into_linear(x), epsilon = 0.000001);
+            assert_relative_eq!(gamma.encode(linear), x, epsilon = 0.000001);
+        }
+
+        let encoded = Rgb::<Gamma<Srgb>, f64>::new(0.5, 0.25, 1.0);
+        let linear: LinSrgb<f64> = DynamicGamma::new(2.2).rgb_into_linear([0.5, 0.25, 1.0]);
+        assert_relative_eq!(linear, encoded.into_linear(), epsilon = 0.000001);
+
+        let components = DynamicGamma::new(2.2).rgb_from_linear(linear);
+        for (&component, &expected) in components.iter().zip(&[0.5, 0.25, 1.0]) {
+            assert_relative_eq!(component, expected, epsilon = 0.000001);
+        }
+
+        let luma: LinLuma<D65, f64> = DynamicGamma::new(2.2).luma_into_linear(0.5);
+        assert_relative_eq!(luma, GammaLuma::new(0.5f64).into_linear(), epsilon = 0.000001);
+        assert_relative_eq!(DynamicGamma::new(2.2).luma_from_linear(luma), 0.5, epsilon = 0.000001);
+    }
+}
@@ -0,0 +1,168 @@
+//! Hybrid Log-Gamma (HLG) and the Rec. 2100 HLG standard.
+//!
+//! HLG is a scene referred transfer function, meaning that converting into
+//! linear gives relative scene light. The display light is then found by
+//! applying the [`ootf`](fn.ootf.html), which depends on the peak luminance of
+//! the display.
+//!
+//! ```
+//! use palette::rgb::Rgb;
+//! use palette::encoding::{hlg, Rec2100Hlg};
+//!
+//! let signal = Rgb::<Rec2100Hlg>::new(0.75, 0.5, 0.25);
+//! let display = hlg::ootf(signal.into_linear(), 1000.0);
+//! ```
+
+use num_traits::Float;
+
+use std::marker::PhantomData;
+
+use {cast, Component};
+use rgb::{Rgb, RgbStandard};
+use encoding::{Linear, Rec2020, TransferFn};
+
+/// The Rec. 2100 standard with the HLG transfer function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2100Hlg;
+
+impl RgbStandard for Rec2100Hlg {
+    type Space = Rec2020;
+    type TransferFn = Hlg;
+}
+
+/// The HLG OETF and its inverse.
+///
+/// Linear values are relative scene light, from `0.0` to `1.0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Hlg;
+
+const A: f64 = 0.17883277;
+const B: f64 = 0.28466892;
+const C: f64 = 0.55991073;
+
+impl TransferFn for Hlg {
+    fn into_linear<T: Float>(x: T) -> T {
+        if x <= cast(0.5) {
+            x * x / cast(3.0)
+        } else {
+            (((x - cast(C)) / cast(A)).exp() + cast(B)) / cast(12.0)
+        }
+    }
+
+    fn from_linear<T: Float>(x: T) -> T {
+        if x <= cast(1.0 / 12.0) {
+            (x.max(T::zero()) * cast(3.0)).sqrt()
+        } else {
+            cast::<T, _>(A) * (x * cast(12.0) - cast(B)).ln() + cast(C)
+        }
+    }
+}
+
+/// The HLG system gamma for a display with the peak luminance
+/// `peak_luminance`, in cd/m².
+///
+/// This is the formula from ITU-R BT.2100, which is defined for peak
+/// luminances from `400` to `2000` cd/m², and gives `1.2` for `1000` cd/m².
+pub fn system_gamma<T: Float>(peak_luminance: T) -> T {
+    cast::<T, _>(1.2) + cast::<T, _>(0.42) * (peak_luminance / cast(1000.0)).log10()
+}
+
+/// Apply the HLG OOTF to scene light, for a display with the peak luminance
+/// `peak_luminance`, in cd/m².
+///
+/// The result is display light relative to the peak luminance, so `1.0` is
+/// the peak of the display. The black level lift is not included.
+pub fn ootf<T: Component + Float>(
+    scene: Rgb<Linear<Rec2020>, T>,
+    peak_luminance: T,
+) -> Rgb<Linear<Rec2020>, T> {
+    let gamma = system_gamma(peak_luminance);
+    let scale = luminance(&scene).powf(gamma - T::one());
+
+    Rgb {
+        red: scene.red * scale,
+        green: scene.green * scale,
+        blue: scene.blue * scale,
+        standard: PhantomData,
+    }
+}
+
+/// Undo the HLG OOTF, turning display light relative to the peak luminance
+/// `peak_luminance`, in cd/m², back into scene light.
+pub fn inverse_ootf<T: Component + Float>(
+    display: Rgb<Linear<Rec2020>, T>,
+    peak_luminance: T,
+) -> Rgb<Linear<Rec2020>, T> {
+    let gamma = system_gamma(peak_luminance);
+    let display_luminance = luminance(&display);
+
+    if display_luminance <= T::zero() {
+        return Rgb::new(T::zero(), T::zero(), T::zero());
+    }
+
+    let scale = display_luminance.powf((T::one() - gamma) / gamma);
+
+    Rgb {
+        red: display.red * scale,
+        green: display.green * scale,
+        blue: display.blue * scale,
+        standard: PhantomData,
+    }
+}
+
+fn luminance<T: Component + Float>(color: &Rgb<Linear<Rec2020>, T>) -> T {
+    color.red * cast(0.2627) + color.green * cast(0.6780) + color.blue * cast(0.0593)
+}
+
+#[cfg(test)]
+mod test {
+    use rgb::Rgb;
+    use encoding::{Linear, Rec2020, TransferFn};
+    use super::{inverse_ootf, ootf, system_gamma, Hlg, Rec2100Hlg};
+
+    #[test]
+    fn oetf() {
+        assert_relative_eq!(Hlg::from_linear(0.0f64), 0.0);
+        assert_relative_eq!(Hlg::from_linear(1.0f64 / 12.0), 0.5, epsilon = 0.000001);
+        assert_relative_eq!(Hlg::from_linear(1.0f64), 1.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn round_trip() {
+        for &x in &[0.0f64, 0.01, 1.0 / 12.0, 0.1, 0.5, 1.0] {
+            assert_relative_eq!(Hlg::into_linear(Hlg::from_linear(x)), x, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn gamma() {
+        assert_relative_eq!(system_gamma(1000.0f64), 1.2);
+        assert_relative_eq!(system_gamma(2000.0f64), 1.3264, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn ootf_gray() {
+        let white = Rgb::<Linear<Rec2020>, f64>::new(1.0, 1.0, 1.0);
+        let gray = Rgb::<Linear<Rec2020>, f64>::new(0.5, 0.5, 0.5);
+
+        assert_relative_eq!(ootf(white, 1000.0), white, epsilon = 0.000001);
+        assert_relative_eq!(
+            ootf(gray, 1000.0),
+            Rgb::new(0.4352753, 0.4352753, 0.4352753),
+            epsilon = 0.000001
+        );
+    }
+
+    #[test]
+    fn ootf_round_trip() {
+        let scene = Rgb::<Rec2100Hlg, f64>::new(0.75, 0.5, 0.25).into_linear();
+        let display = ootf(scene, 1500.0);
+
+        assert_relative_eq!(inverse_ootf(display, 1500.0), scene, epsilon = 0.000001);
+        assert_relative_eq!(
+            Rgb::<Rec2100Hlg, f64>::from_linear(scene),
+            Rgb::new(0.75, 0.5, 0.25),
+            epsilon = 0.000001
+        );
+    }
+}
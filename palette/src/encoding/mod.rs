@@ -2,13 +2,31 @@
 
 use num_traits::Float;
 
+use Component;
+use rgb::{Rgb, RgbSpace};
+use luma::Luma;
+use white_point::WhitePoint;
+
 pub use self::srgb::Srgb;
-pub use self::gamma::{F2p2, Gamma};
+pub use self::gamma::{DynamicGamma, F1p8, F2p2, F2p4, F2p6, Gamma};
+pub use self::gamma::{Nits0, Nits0p1, Nits100, Nits10000, Nits203};
 pub use self::linear::Linear;
+pub use self::scrgb::{ExtendedSrgb, ScRgb};
+pub use self::rec709::{Bt1886, Rec709};
+pub use self::rec2020::Rec2020;
+pub use self::pq::{Pq, Rec2100Pq};
+pub use self::hlg::{Hlg, Rec2100Hlg};
+pub use self::curve::{ParametricCurve, SampledCurve};
 
 pub mod srgb;
 pub mod gamma;
 pub mod linear;
+pub mod scrgb;
+pub mod rec709;
+pub mod rec2020;
+pub mod pq;
+pub mod hlg;
+pub mod curve;
 pub mod pixel;
 
 /// A transfer function to and from linear space.
@@ -19,3 +37,46 @@ pub trait TransferFn {
     /// Convert the color component `x` into linear space.
     fn into_linear<T: Float>(x: T) -> T;
 }
+
+/// A transfer function that is described by a value, such as a runtime gamma,
+/// a parametric curve or a sampled curve.
+///
+/// This is the counterpart of [`TransferFn`](trait.TransferFn.html) for
+/// curves that can't be represented as types. Since an RGB or luma standard
+/// can't carry the curve, the encoded side is represented by raw components
+/// and only the linear side is a color type.
+pub trait TransferCurve<T: Component + Float> {
+    /// Encode the linear color component `x`.
+    fn encode(&self, x: T) -> T;
+
+    /// Decode the encoded color component `x` into linear space.
+    fn decode(&self, x: T) -> T;
+
+    /// Convert encoded RGB components, `[red, green, blue]`, into linear RGB.
+    fn rgb_into_linear<S: RgbSpace>(&self, encoded: [T; 3]) -> Rgb<Linear<S>, T> {
+        Rgb::new(
+            self.decode(encoded[0]),
+            self.decode(encoded[1]),
+            self.decode(encoded[2]),
+        )
+    }
+
+    /// Convert linear RGB into encoded RGB components, `[red, green, blue]`.
+    fn rgb_from_linear<S: RgbSpace>(&self, color: Rgb<Linear<S>, T>) -> [T; 3] {
+        [
+            self.encode(color.red),
+            self.encode(color.green),
+            self.encode(color.blue),
+        ]
+    }
+
+    /// Convert an encoded luminance component into linear luminance.
+    fn luma_into_linear<Wp: WhitePoint>(&self, encoded: T) -> Luma<Linear<Wp>, T> {
+        Luma::new(self.decode(encoded))
+    }
+
+    /// Convert linear luminance into an encoded luminance component.
+    fn luma_from_linear<Wp: WhitePoint>(&self, color: Luma<Linear<Wp>, T>) -> T {
+        self.encode(color.luma)
+    }
+}
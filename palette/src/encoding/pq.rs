@@ -0,0 +1,182 @@
+//! The SMPTE ST 2084 perceptual quantizer (PQ) and the Rec. 2100 PQ standard.
+//!
+//! PQ encodes absolute luminance from `0` to `10000` cd/m² as code values from
+//! `0.0` to `1.0`. It's the transfer function of HDR10 content.
+//!
+//! ```
+//! use palette::encoding::pq;
+//!
+//! let code = pq::nits_to_pq_10bit(1000.0);
+//! assert_eq!(code, 769);
+//! ```
+
+use std::marker::PhantomData;
+
+use num_traits::Float;
+
+use cast;
+use rgb::RgbStandard;
+use encoding::{Rec2020, TransferFn};
+use encoding::gamma::{Nits10000, Number};
+
+/// The Rec. 2100 standard with the PQ transfer function.
+///
+/// Linear `1.0` corresponds to the reference luminance `Ref`, which is the PQ
+/// peak luminance of `10000` cd/m² by default.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2100Pq<Ref: Number = Nits10000>(PhantomData<Ref>);
+
+impl<Ref: Number> RgbStandard for Rec2100Pq<Ref> {
+    type Space = Rec2020;
+    type TransferFn = Pq<Ref>;
+}
+
+/// The PQ transfer function, with linear values relative to the reference
+/// luminance `Ref`.
+///
+/// Converting into linear gives the absolute luminance divided by `Ref`, so a
+/// reference of `Nits100` makes `1.0` correspond to `100` cd/m².
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Pq<Ref: Number = Nits10000>(PhantomData<Ref>);
+
+impl<Ref: Number> TransferFn for Pq<Ref> {
+    fn into_linear<T: Float>(x: T) -> T {
+        pq_to_nits(x) / cast(Ref::VALUE)
+    }
+
+    fn from_linear<T: Float>(x: T) -> T {
+        nits_to_pq(x * cast(Ref::VALUE))
+    }
+}
+
+const M1: f64 = 2610.0 / 16384.0;
+const M2: f64 = 2523.0 / 4096.0 * 128.0;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 4096.0 * 32.0;
+const C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// Convert an absolute luminance in cd/m² to a PQ code value from `0.0` to
+/// `1.0`.
+pub fn nits_to_pq<T: Float>(nits: T) -> T {
+    let y = (nits / cast(10000.0)).max(T::zero()).powf(cast(M1));
+    ((cast::<T, _>(C1) + cast::<T, _>(C2) * y) / (T::one() + cast::<T, _>(C3) * y)).powf(cast(M2))
+}
+
+/// Convert a PQ code value from `0.0` to `1.0` to an absolute luminance in
+/// cd/m².
+pub fn pq_to_nits<T: Float>(code: T) -> T {
+    let e = code.max(T::zero()).powf(T::one() / cast(M2));
+    let y = ((e - cast(C1)).max(T::zero()) / (cast::<T, _>(C2) - cast::<T, _>(C3) * e))
+        .powf(T::one() / cast(M1));
+    y * cast(10000.0)
+}
+
+/// Convert an absolute luminance in cd/m² to a full range 16 bit PQ code
+/// value.
+pub fn nits_to_pq_u16(nits: f32) -> u16 {
+    quantize(nits_to_pq(nits as f64), 0.0, 65535.0)
+}
+
+/// Convert a full range 16 bit PQ code value to an absolute luminance in
+/// cd/m².
+pub fn pq_u16_to_nits(code: u16) -> f32 {
+    pq_to_nits(code as f64 / 65535.0) as f32
+}
+
+/// Convert an absolute luminance in cd/m² to a full range 10 bit PQ code
+/// value, from `0` to `1023`.
+pub fn nits_to_pq_10bit(nits: f32) -> u16 {
+    quantize(nits_to_pq(nits as f64), 0.0, 1023.0)
+}
+
+/// Convert a full range 10 bit PQ code value, from `0` to `1023`, to an
+/// absolute luminance in cd/m².
+pub fn pq_10bit_to_nits(code: u16) -> f32 {
+    pq_to_nits(code.min(1023) as f64 / 1023.0) as f32
+}
+
+/// Convert an absolute luminance in cd/m² to a narrow range 10 bit PQ code
+/// value, from `64` to `940`, as used in HDR10 video.
+pub fn nits_to_pq_10bit_narrow(nits: f32) -> u16 {
+    quantize(nits_to_pq(nits as f64), 64.0, 876.0)
+}
+
+/// Convert a narrow range 10 bit PQ code value, from `64` to `940`, to an
+/// absolute luminance in cd/m².
+pub fn pq_10bit_narrow_to_nits(code: u16) -> f32 {
+    let code = code.max(64).min(940);
+    pq_to_nits((code - 64) as f64 / 876.0) as f32
+}
+
+fn quantize(code: f64, offset: f64, scale: f64) -> u16 {
+    (offset + code.max(0.0).min(1.0) * scale).round() as u16
+}
+
+#[cfg(test)]
+mod test {
+    use {Limited, Xyz};
+    use rgb::Rgb;
+    use encoding::{Linear, Rec2020, TransferFn};
+    use encoding::gamma::{Nits100, Nits10000};
+    use super::{nits_to_pq, nits_to_pq_10bit, nits_to_pq_10bit_narrow, nits_to_pq_u16,
+                pq_10bit_narrow_to_nits, pq_10bit_to_nits, pq_to_nits, pq_u16_to_nits, Pq,
+                Rec2100Pq};
+
+    #[test]
+    fn reference_values() {
+        assert_relative_eq!(nits_to_pq(0.0f64), 0.0, epsilon = 0.000001);
+        assert_relative_eq!(nits_to_pq(100.0f64), 0.5080784, epsilon = 0.000001);
+        assert_relative_eq!(nits_to_pq(1000.0f64), 0.7518271, epsilon = 0.000001);
+        assert_relative_eq!(nits_to_pq(10000.0f64), 1.0, epsilon = 0.000001);
+        assert_relative_eq!(pq_to_nits(0.0f64), 0.0);
+        assert_relative_eq!(pq_to_nits(1.0f64), 10000.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn round_trip() {
+        for &nits in &[0.01f64, 0.1, 1.0, 100.0, 203.0, 1000.0, 4000.0, 10000.0] {
+            assert_relative_eq!(pq_to_nits(nits_to_pq(nits)), nits, max_relative = 0.000001);
+        }
+    }
+
+    #[test]
+    fn encoded_range() {
+        let clamped = Rgb::<Rec2100Pq<Nits100>, f32>::new(2.0, 0.5, 0.5).clamp();
+        assert_relative_eq!(clamped.red, 1.0);
+        assert!(Rgb::<Rec2100Pq<Nits100>, f32>::new(1.0, 0.5, 0.0).is_valid());
+    }
+
+    #[test]
+    fn relative_to_reference() {
+        assert_relative_eq!(Pq::<Nits10000>::into_linear(0.5080784f64), 0.01, epsilon = 0.000001);
+        assert_relative_eq!(Pq::<Nits100>::into_linear(0.5080784f64), 1.0, epsilon = 0.0001);
+        assert_relative_eq!(Pq::<Nits100>::from_linear(10.0f64), 0.7518271, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn integer_code_values() {
+        assert_eq!(nits_to_pq_10bit(0.0), 0);
+        assert_eq!(nits_to_pq_10bit(100.0), 520);
+        assert_eq!(nits_to_pq_10bit(1000.0), 769);
+        assert_eq!(nits_to_pq_10bit(10000.0), 1023);
+        assert_eq!(nits_to_pq_10bit(20000.0), 1023);
+        assert_eq!(nits_to_pq_10bit_narrow(0.0), 64);
+        assert_eq!(nits_to_pq_10bit_narrow(1000.0), 723);
+        assert_eq!(nits_to_pq_10bit_narrow(10000.0), 940);
+        assert_eq!(nits_to_pq_u16(1000.0), 49271);
+
+        assert_relative_eq!(pq_10bit_to_nits(1023), 10000.0, max_relative = 0.0001);
+        assert_relative_eq!(pq_10bit_narrow_to_nits(940), 10000.0, max_relative = 0.0001);
+        assert_relative_eq!(pq_10bit_narrow_to_nits(0), 0.0);
+        assert_relative_eq!(pq_u16_to_nits(49271), 1000.0, max_relative = 0.0001);
+    }
+
+    #[test]
+    fn rec2100_into_linear() {
+        let color = Rgb::<Rec2100Pq<Nits100>, f64>::new(0.5080784, 0.5080784, 0.5080784);
+        let linear: Rgb<Linear<Rec2020>, f64> = color.into_linear();
+        let xyz: Xyz<_, f64> = linear.into();
+
+        assert_relative_eq!(xyz.y, 1.0, epsilon = 0.0001);
+    }
+}
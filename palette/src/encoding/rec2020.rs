@@ -0,0 +1,69 @@
+//! The Rec. 2020 standard.
+
+use num_traits::Float;
+
+use rgb::{Primaries, RgbSpace, RgbStandard};
+use encoding::{Rec709, TransferFn};
+use white_point::{D65, WhitePoint};
+use {cast, Component, Yxy};
+
+/// The Rec. 2020 color space, used for UHDTV and HDR video.
+///
+/// Its transfer function is the Rec. 709 OETF, with the same constants as in
+/// Rec. 709. Those are the constants Rec. 2020 specifies for 10 bit signals,
+/// while 12 bit signals use slightly more precise ones that aren't provided
+/// here.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2020;
+
+impl Primaries for Rec2020 {
+    fn red<Wp: WhitePoint, T: Component + Float>() -> Yxy<Wp, T> {
+        Yxy::with_wp(cast(0.7080), cast(0.2920), cast(0.262700))
+    }
+    fn green<Wp: WhitePoint, T: Component + Float>() -> Yxy<Wp, T> {
+        Yxy::with_wp(cast(0.1700), cast(0.7970), cast(0.677998))
+    }
+    fn blue<Wp: WhitePoint, T: Component + Float>() -> Yxy<Wp, T> {
+        Yxy::with_wp(cast(0.1310), cast(0.0460), cast(0.059302))
+    }
+}
+
+impl RgbSpace for Rec2020 {
+    type Primaries = Rec2020;
+    type WhitePoint = D65;
+}
+
+impl RgbStandard for Rec2020 {
+    type Space = Rec2020;
+    type TransferFn = Rec2020;
+}
+
+impl TransferFn for Rec2020 {
+    fn into_linear<T: Float>(x: T) -> T {
+        Rec709::into_linear(x)
+    }
+
+    fn from_linear<T: Float>(x: T) -> T {
+        Rec709::from_linear(x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matrix::rgb_to_xyz_matrix;
+    use super::Rec2020;
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[test]
+    fn rgb_to_xyz() {
+        let expected = [
+            0.6369580, 0.1446169, 0.1688810,
+            0.2627002, 0.6779981, 0.0593017,
+            0.0000000, 0.0280727, 1.0609851,
+        ];
+        let computed = rgb_to_xyz_matrix::<Rec2020, f64>();
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.001)
+        }
+    }
+}
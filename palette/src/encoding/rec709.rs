@@ -0,0 +1,148 @@
+//! The Rec. 709 and BT.1886 standards.
+//!
+//! Rec. 709 defines the primaries and the camera side (OETF) transfer function
+//! for HDTV, while BT.1886 defines the display side (EOTF) transfer function
+//! for the same signals. The primaries and white point are the same as in sRGB.
+
+use std::marker::PhantomData;
+
+use num_traits::Float;
+
+use cast;
+use rgb::{RgbSpace, RgbStandard};
+use luma::LumaStandard;
+use encoding::{Srgb, TransferFn};
+use encoding::gamma::{Nits0, Nits100, Number};
+use white_point::{D65, WhitePoint};
+
+/// The Rec. 709 standard, with the camera side transfer function.
+///
+/// The transfer function is the Rec. 709 OETF, which maps scene light to
+/// signal values. Converting into linear gives relative scene light.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec709;
+
+impl RgbStandard for Rec709 {
+    type Space = Srgb;
+    type TransferFn = Rec709;
+}
+
+impl LumaStandard for Rec709 {
+    type WhitePoint = D65;
+    type TransferFn = Rec709;
+}
+
+impl TransferFn for Rec709 {
+    fn into_linear<T: Float>(x: T) -> T {
+        if x < cast(0.081) {
+            x / cast(4.5)
+        } else {
+            ((x + cast(0.099)) / cast(1.099)).powf(T::one() / cast(0.45))
+        }
+    }
+
+    fn from_linear<T: Float>(x: T) -> T {
+        if x < cast(0.018) {
+            x * cast(4.5)
+        } else {
+            x.powf(cast(0.45)) * cast(1.099) - cast(0.099)
+        }
+    }
+}
+
+/// The BT.1886 standard, with the display side transfer function.
+///
+/// The transfer function is the BT.1886 EOTF for a display with the white
+/// luminance `Lw` and the black luminance `Lb`, both in cd/m². Converting into
+/// linear gives the displayed luminance relative to the white luminance, so
+/// white is `1.0` and black is `Lb / Lw`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bt1886<S = Srgb, Lw: Number = Nits100, Lb: Number = Nits0>(PhantomData<(S, Lw, Lb)>);
+
+impl<S: RgbSpace, Lw: Number, Lb: Number> RgbStandard for Bt1886<S, Lw, Lb> {
+    type Space = S;
+    type TransferFn = Bt1886Fn<Lw, Lb>;
+}
+
+impl<Wp: WhitePoint, Lw: Number, Lb: Number> LumaStandard for Bt1886<Wp, Lw, Lb> {
+    type WhitePoint = Wp;
+    type TransferFn = Bt1886Fn<Lw, Lb>;
+}
+
+/// The BT.1886 EOTF for a display with the white luminance `Lw` and the black
+/// luminance `Lb`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bt1886Fn<Lw: Number = Nits100, Lb: Number = Nits0>(PhantomData<(Lw, Lb)>);
+
+impl<Lw: Number, Lb: Number> Bt1886Fn<Lw, Lb> {
+    /// The gain `a` and black lift `b` of the EOTF, with the gain relative to
+    /// the white luminance.
+    fn parameters<T: Float>() -> (T, T) {
+        let gamma: T = cast(2.4);
+        let white: T = cast(Lw::VALUE);
+        let black: T = cast(Lb::VALUE);
+
+        let white_root = white.powf(T::one() / gamma);
+        let black_root = black.powf(T::one() / gamma);
+        let gain = (white_root - black_root).powf(gamma) / white;
+        let lift = black_root / (white_root - black_root);
+
+        (gain, lift)
+    }
+}
+
+impl<Lw: Number, Lb: Number> TransferFn for Bt1886Fn<Lw, Lb> {
+    fn into_linear<T: Float>(x: T) -> T {
+        let (gain, lift) = Self::parameters::<T>();
+        gain * (x + lift).max(T::zero()).powf(cast(2.4))
+    }
+
+    fn from_linear<T: Float>(x: T) -> T {
+        let (gain, lift) = Self::parameters::<T>();
+        (x.max(T::zero()) / gain).powf(T::one() / cast(2.4)) - lift
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use encoding::TransferFn;
+    use encoding::gamma::{Nits0p1, Nits100};
+    use super::{Bt1886Fn, Rec709};
+
+    #[test]
+    fn rec709_oetf() {
+        assert_relative_eq!(Rec709::from_linear(0.0f64), 0.0);
+        assert_relative_eq!(Rec709::from_linear(0.018f64), 0.081, epsilon = 0.001);
+        assert_relative_eq!(Rec709::from_linear(0.5f64), 0.7055151, epsilon = 0.000001);
+        assert_relative_eq!(Rec709::from_linear(1.0f64), 1.0);
+    }
+
+    #[test]
+    fn rec709_round_trip() {
+        for &x in &[0.0f64, 0.01, 0.018, 0.02, 0.3, 0.9, 1.0] {
+            assert_relative_eq!(Rec709::into_linear(Rec709::from_linear(x)), x, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn bt1886_zero_black_is_pure_gamma() {
+        type Eotf = Bt1886Fn;
+
+        assert_relative_eq!(Eotf::into_linear(0.0f64), 0.0);
+        assert_relative_eq!(Eotf::into_linear(0.5f64), 0.5f64.powf(2.4), epsilon = 0.000001);
+        assert_relative_eq!(Eotf::into_linear(1.0f64), 1.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn bt1886_with_black_level() {
+        type Eotf = Bt1886Fn<Nits100, Nits0p1>;
+
+        assert_relative_eq!(Eotf::into_linear(0.0f64), 0.001, epsilon = 0.000001);
+        assert_relative_eq!(Eotf::into_linear(0.5f64), 0.2160491, epsilon = 0.000001);
+        assert_relative_eq!(Eotf::into_linear(1.0f64), 1.0, epsilon = 0.000001);
+
+        for &x in &[0.0f64, 0.1, 0.5, 1.0] {
+            assert_relative_eq!(Eotf::from_linear(Eotf::into_linear(x)), x, epsilon = 0.000001);
+        }
+    }
+}
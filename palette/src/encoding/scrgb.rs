@@ -0,0 +1,126 @@
+//! Extended range sRGB and scRGB.
+//!
+//! These standards share their primaries and white point with sRGB, but allow
+//! component values below `0.0` and above `1.0`. That makes it possible to
+//! represent colors outside the sRGB gamut and brighter than its white, which
+//! is what HDR compositors use them for.
+
+use num_traits::Float;
+
+use rgb::RgbStandard;
+use encoding::{Srgb, TransferFn};
+use encoding::linear::LinearFn;
+
+/// The extended sRGB standard.
+///
+/// Extended sRGB uses the sRGB transfer function, mirrored around zero, so
+/// negative values are encoded as the negated encoding of their absolute
+/// value. The valid range covers the same colors as [`ScRgb`](struct.ScRgb.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExtendedSrgb;
+
+impl RgbStandard for ExtendedSrgb {
+    type Space = Srgb;
+    type TransferFn = ExtendedSrgb;
+
+    fn component_range() -> (f64, f64) {
+        let (min, max) = ScRgb::component_range();
+        (ExtendedSrgb::from_linear(min), ExtendedSrgb::from_linear(max))
+    }
+}
+
+impl TransferFn for ExtendedSrgb {
+    fn into_linear<T: Float>(x: T) -> T {
+        x.signum() * Srgb::into_linear(x.abs())
+    }
+
+    fn from_linear<T: Float>(x: T) -> T {
+        x.signum() * Srgb::from_linear(x.abs())
+    }
+}
+
+/// The scRGB standard.
+///
+/// scRGB is linear sRGB with an extended range, where `1.0` is the sRGB white
+/// and values from `-0.5` to just below `7.5` are valid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScRgb;
+
+impl RgbStandard for ScRgb {
+    type Space = Srgb;
+    type TransferFn = LinearFn;
+
+    fn component_range() -> (f64, f64) {
+        (-0.5, 7.4999)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {Limited, LinSrgb};
+    use rgb::{ExtendedSrgb, Rgb, ScRgb};
+    use encoding::{self, Srgb, TransferFn};
+    use super::ExtendedSrgb as ExtendedSrgbFn;
+
+    #[test]
+    fn mirrored_transfer() {
+        for &x in &[0.0, 0.01, 0.04045, 0.2, 0.5, 1.0, 2.5] {
+            let linear: f64 = ExtendedSrgbFn::into_linear(x);
+            assert_relative_eq!(linear, Srgb::into_linear(x));
+            assert_relative_eq!(ExtendedSrgbFn::into_linear(-x), -linear);
+            assert_relative_eq!(ExtendedSrgbFn::from_linear(-linear), -x, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn extended_into_linear() {
+        let color = ExtendedSrgb::new(-0.5f64, 0.5, 1.5);
+        let linear = color.into_linear();
+
+        assert_relative_eq!(linear.red, -Srgb::into_linear(0.5f64));
+        assert_relative_eq!(linear.green, Srgb::into_linear(0.5f64));
+        assert_relative_eq!(linear.blue, Srgb::into_linear(1.5f64));
+        assert_relative_eq!(ExtendedSrgb::from_linear(linear), color, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn scrgb_is_linear_srgb() {
+        let color = ScRgb::new(-0.25f64, 0.5, 4.0);
+        let linear: LinSrgb<f64> = color.into_linear();
+
+        assert_eq!(linear, LinSrgb::new(-0.25, 0.5, 4.0));
+    }
+
+    #[test]
+    fn scrgb_ranges() {
+        assert_ranges!{
+            Rgb<encoding::ScRgb, f64>;
+            limited {
+                red: -0.5 => 7.4999,
+                green: -0.5 => 7.4999,
+                blue: -0.5 => 7.4999
+            }
+            limited_min {}
+            unlimited {}
+        }
+    }
+
+    #[test]
+    fn extended_srgb_ranges() {
+        let min = ExtendedSrgbFn::from_linear(-0.5f64);
+        let max = ExtendedSrgbFn::from_linear(7.4999f64);
+
+        assert!(ExtendedSrgb::new(-0.5f64, 0.5, 1.5).is_valid());
+        assert!(!ExtendedSrgb::new(-0.5f64, 0.5, 3.0).is_valid());
+        assert_relative_eq!(
+            ExtendedSrgb::new(-1.0f64, 0.5, 3.0).clamp(),
+            ExtendedSrgb::new(min, 0.5, max)
+        );
+    }
+
+    #[test]
+    fn integer_components_keep_their_range() {
+        assert!(ScRgb::<u8>::new(0, 128, 255).is_valid());
+        assert_eq!(ScRgb::<u8>::new(0, 128, 255).clamp(), ScRgb::new(0, 128, 255));
+    }
+}
@@ -0,0 +1,509 @@
+//!Mapping colors into the gamut of an RGB space.
+//!
+//![`Limited::clamp`](../trait.Limited.html#tymethod.clamp) clips each channel
+//!independently, which can shift the hue of colors that are far outside of
+//!the gamut. The [`gamut_map`](fn.gamut_map.html) function offers
+//!alternatives that keep the hue, following the CSS Color 4 approach.
+//!
+//!```
+//!use palette::{Lch, Srgb};
+//!use palette::gamut::{gamut_map, GamutMapping};
+//!
+//!//A saturated green from a wide gamut space.
+//!let color = Lch::new(80.0, 120.0, 140.0);
+//!
+//!let clipped: Srgb = gamut_map(color, GamutMapping::Clip);
+//!let mapped: Srgb = gamut_map(color, GamutMapping::ReduceChroma);
+//!```
+//!
+//!It's also possible to query the gamut, either directly or through a
+//![`GamutBoundary`](struct.GamutBoundary.html) that is sampled once and can
+//!then be queried quickly.
+//!
+//!```
+//!use palette::{Lch, LabHue};
+//!use palette::gamut::{max_chroma, InGamut};
+//!use palette::encoding::Srgb;
+//!
+//!let color = Lch::new(50.0f64, 40.0, 30.0);
+//!assert!(color.is_in_gamut::<Srgb>());
+//!
+//!let limit = max_chroma::<Srgb, _, f64>(50.0, LabHue::from(30.0));
+//!assert!(limit > 40.0);
+//!```
+
+use num_traits::Float;
+
+use std::marker::PhantomData;
+
+use {cast, Component, IntoColor, LabHue, Lch};
+use color_difference::ciede2000;
+use rgb::{Rgb, RgbSpace, RgbStandard};
+use encoding::Linear;
+use white_point::WhitePoint;
+
+///Strategies for mapping colors into a gamut.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GamutMapping {
+    ///Clip each linear RGB channel to the range `[0.0, 1.0]`. This is the
+    ///fastest strategy, but it may change the hue.
+    Clip,
+
+    ///Reduce the `Lch` chroma at constant lightness and hue, as in CSS Color
+    ///4, until clipping the result differs by less than a just noticeable
+    ///difference from the reduced color. The difference is measured with
+    ///CIEDE2000, and the just noticeable difference is `2.0`.
+    ReduceChroma,
+
+    ///Move the color in `Lch` along a straight line towards the neutral gray
+    ///with the lightness `50`, at constant hue, until it reaches the gamut
+    ///boundary. This changes both lightness and chroma, which preserves more
+    ///chroma for very light and very dark colors.
+    Project,
+}
+
+///The CIEDE2000 difference below which clipping is considered invisible.
+const JUST_NOTICEABLE_DIFFERENCE: f64 = 2.0;
+
+///The tolerance of the chroma search.
+const CHROMA_EPSILON: f64 = 0.0001;
+
+///Map a color into the gamut of the RGB standard `S`, using the strategy
+///`mapping`.
+///
+///Colors that are already within the gamut are only converted.
+pub fn gamut_map<C, S, Wp, T>(color: C, mapping: GamutMapping) -> Rgb<S, T>
+where
+    C: IntoColor<Wp, T>,
+    S: RgbStandard,
+    S::Space: RgbSpace<WhitePoint = Wp>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let lch = color.into_lch();
+    let rgb: Rgb<Linear<S::Space>, T> = lch.into_rgb();
+
+    let mapped = if fits(&rgb) {
+        rgb
+    } else {
+        match mapping {
+            GamutMapping::Clip => clip(&rgb),
+            GamutMapping::ReduceChroma => reduce_chroma(lch),
+            GamutMapping::Project => project(lch),
+        }
+    };
+
+    Rgb::from_linear(clip(&mapped))
+}
+
+///Check if colors are within the gamut of an RGB space.
+///
+///This is implemented for every color that can be converted through `Xyz`.
+pub trait InGamut<Wp = ::white_point::D65, T = f32>: IntoColor<Wp, T>
+where
+    T: Component + Float,
+    Wp: WhitePoint,
+{
+    ///Check if the color is within the gamut of the RGB space `S`.
+    fn is_in_gamut<S: RgbSpace<WhitePoint = Wp>>(self) -> bool {
+        fits(&self.into_rgb::<S>())
+    }
+}
+
+impl<C, Wp, T> InGamut<Wp, T> for C
+where
+    C: IntoColor<Wp, T>,
+    T: Component + Float,
+    Wp: WhitePoint,
+{
+}
+
+///The largest chroma that is within the gamut of the RGB space `S`, for the
+///`Lch` lightness `l` and hue `hue`.
+///
+///The chroma is found with a binary search, with a precision of about
+///`0.0001`. Lightnesses outside of `[0.0, 100.0]` give `0.0`.
+pub fn max_chroma<S, Wp, T>(l: T, hue: LabHue<T>) -> T
+where
+    S: RgbSpace<WhitePoint = Wp>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let fits_with = |chroma: T| fits(&Lch::<Wp, T>::with_wp(l, chroma, hue).into_rgb::<S>());
+
+    if !fits_with(T::zero()) {
+        return T::zero();
+    }
+
+    let (mut low, mut high) = (T::zero(), cast::<T, _>(MAX_SEARCH_CHROMA));
+    while high - low > cast(CHROMA_EPSILON) {
+        let middle = (low + high) / cast(2.0);
+        if fits_with(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    low
+}
+
+///The upper limit of the chroma search. No RGB space with real primaries has
+///colors with more chroma than this.
+const MAX_SEARCH_CHROMA: f64 = 400.0;
+
+///A sampled description of the gamut boundary of the RGB space `S`, in `Lch`.
+///
+///The boundary is stored as the maximum chroma at evenly spaced lightnesses
+///and hues, and is interpolated between the samples. It's an approximation,
+///so colors very close to the boundary may be misclassified, but queries are
+///much faster than [`max_chroma`](fn.max_chroma.html).
+#[derive(Clone, Debug)]
+pub struct GamutBoundary<S, T = f32>
+where
+    T: Float,
+{
+    lightness_steps: usize,
+    hue_steps: usize,
+    chroma: Vec<T>,
+    space: PhantomData<S>,
+}
+
+impl<S, Wp, T> GamutBoundary<S, T>
+where
+    S: RgbSpace<WhitePoint = Wp>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    ///Sample the boundary at `lightness_steps` lightnesses from `0.0` to
+    ///`100.0` and `hue_steps` hues around the hue circle.
+    ///
+    ///# Panics
+    ///
+    ///Panics if there are fewer than two lightness steps or no hue steps.
+    pub fn new(lightness_steps: usize, hue_steps: usize) -> GamutBoundary<S, T> {
+        assert!(
+            lightness_steps >= 2 && hue_steps >= 1,
+            "a gamut boundary needs at least two lightness steps and one hue step"
+        );
+
+        let mut chroma = Vec::with_capacity(lightness_steps * hue_steps);
+        for l in 0..lightness_steps {
+            let lightness = Self::lightness_at(lightness_steps, l);
+            for h in 0..hue_steps {
+                let hue = Self::hue_at(hue_steps, h);
+                chroma.push(max_chroma::<S, Wp, T>(lightness, hue.into()));
+            }
+        }
+
+        GamutBoundary {
+            lightness_steps: lightness_steps,
+            hue_steps: hue_steps,
+            chroma: chroma,
+            space: PhantomData,
+        }
+    }
+
+    ///The interpolated maximum chroma for the lightness `l` and hue `hue`.
+    ///
+    ///Lightnesses outside of `[0.0, 100.0]`, and NaN lightnesses or hues,
+    ///give `0.0`, like for [`max_chroma`](fn.max_chroma.html).
+    pub fn max_chroma(&self, l: T, hue: LabHue<T>) -> T {
+        let max_l: T = cast(100.0);
+        let hue = hue.to_positive_degrees();
+        if !(l >= T::zero() && l <= max_l) || hue.is_nan() {
+            return T::zero();
+        }
+
+        let l_position = l / max_l * T::from(self.lightness_steps - 1).unwrap();
+        let l_index = l_position
+            .floor()
+            .to_usize()
+            .unwrap()
+            .min(self.lightness_steps - 2);
+        let l_fraction = l_position - T::from(l_index).unwrap();
+
+        let h_position = hue / cast(360.0) * T::from(self.hue_steps).unwrap();
+        let h_index = h_position.floor().to_usize().unwrap() % self.hue_steps;
+        let h_fraction = h_position - h_position.floor();
+        let h_next = (h_index + 1) % self.hue_steps;
+
+        let sample = |l: usize, h: usize| self.chroma[l * self.hue_steps + h];
+        let low = sample(l_index, h_index)
+            + (sample(l_index, h_next) - sample(l_index, h_index)) * h_fraction;
+        let high = sample(l_index + 1, h_index)
+            + (sample(l_index + 1, h_next) - sample(l_index + 1, h_index)) * h_fraction;
+
+        low + (high - low) * l_fraction
+    }
+
+    ///Check if a color is within the sampled boundary.
+    pub fn contains<C: IntoColor<Wp, T>>(&self, color: C) -> bool {
+        let lch = color.into_lch();
+        lch.chroma <= self.max_chroma(lch.l, lch.hue)
+    }
+
+    ///The signed distance in chroma from a color to the sampled boundary, at
+    ///the color's lightness and hue. Positive values are inside the boundary.
+    pub fn chroma_margin<C: IntoColor<Wp, T>>(&self, color: C) -> T {
+        let lch = color.into_lch();
+        self.max_chroma(lch.l, lch.hue) - lch.chroma
+    }
+
+    fn lightness_at(steps: usize, index: usize) -> T {
+        T::from(index).unwrap() / T::from(steps - 1).unwrap() * cast(100.0)
+    }
+
+    fn hue_at(steps: usize, index: usize) -> T {
+        T::from(index).unwrap() / T::from(steps).unwrap() * cast(360.0)
+    }
+}
+
+///Check if a linear RGB color is within the range `[0.0, 1.0]`, with a small
+///tolerance for rounding errors.
+///
+///This is the check that is used by the rest of this module, and by
+///[`contrast::fix_contrast`](../contrast/fn.fix_contrast.html).
+pub fn fits<S: RgbSpace, T: Component + Float>(rgb: &Rgb<Linear<S>, T>) -> bool {
+    let epsilon: T = cast(0.000001);
+    let low = -epsilon;
+    let high = T::one() + epsilon;
+
+    rgb.red >= low && rgb.red <= high && rgb.green >= low && rgb.green <= high
+        && rgb.blue >= low && rgb.blue <= high
+}
+
+fn clip<S: RgbSpace, T: Component + Float>(rgb: &Rgb<Linear<S>, T>) -> Rgb<Linear<S>, T> {
+    Rgb::new(
+        rgb.red.max(T::zero()).min(T::one()),
+        rgb.green.max(T::zero()).min(T::one()),
+        rgb.blue.max(T::zero()).min(T::one()),
+    )
+}
+
+fn reduce_chroma<S, Wp, T>(lch: Lch<Wp, T>) -> Rgb<Linear<S>, T>
+where
+    S: RgbSpace<WhitePoint = Wp>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    if lch.l >= cast(100.0) {
+        return Rgb::new(T::one(), T::one(), T::one());
+    }
+    if lch.l <= T::zero() {
+        return Rgb::new(T::zero(), T::zero(), T::zero());
+    }
+
+    let jnd: T = cast(JUST_NOTICEABLE_DIFFERENCE);
+    let epsilon: T = cast(CHROMA_EPSILON);
+
+    let difference = |current: &Lch<Wp, T>, clipped: &Rgb<Linear<S>, T>| {
+        ciede2000(
+            &current.into_lab(),
+            &clipped.into_lab(),
+            T::one(),
+            T::one(),
+            T::one(),
+        )
+    };
+
+    let mut current = lch;
+    let mut clipped = clip(&current.into_rgb::<S>());
+    if difference(&current, &clipped) < jnd {
+        return clipped;
+    }
+
+    let (mut min, mut max) = (T::zero(), lch.chroma);
+    let mut min_in_gamut = true;
+
+    while max - min > epsilon {
+        current.chroma = (min + max) / cast(2.0);
+        let rgb: Rgb<Linear<S>, T> = current.into_rgb();
+
+        if min_in_gamut && fits(&rgb) {
+            min = current.chroma;
+            continue;
+        }
+
+        clipped = clip(&rgb);
+        let e = difference(&current, &clipped);
+        if e < jnd {
+            if jnd - e < epsilon {
+                return clipped;
+            }
+
+            min_in_gamut = false;
+            min = current.chroma;
+        } else {
+            max = current.chroma;
+        }
+    }
+
+    clipped
+}
+
+fn project<S, Wp, T>(lch: Lch<Wp, T>) -> Rgb<Linear<S>, T>
+where
+    S: RgbSpace<WhitePoint = Wp>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let gray: T = cast(50.0);
+    let at = |position: T| -> Rgb<Linear<S>, T> {
+        Lch::with_wp(
+            gray + (lch.l - gray) * position,
+            lch.chroma * position,
+            lch.hue,
+        ).into_rgb()
+    };
+
+    let (mut inside, mut outside) = (T::zero(), T::one());
+    while outside - inside > cast(CHROMA_EPSILON / 100.0) {
+        let middle = (inside + outside) / cast(2.0);
+        if fits(&at(middle)) {
+            inside = middle;
+        } else {
+            outside = middle;
+        }
+    }
+
+    clip(&at(inside))
+}
+
+#[cfg(test)]
+mod test {
+    use {IntoColor, LabHue, Lch, LinSrgb, Srgb};
+    use color_difference::ColorDifference;
+    use rgb::Rgb;
+    use encoding::{self, Linear};
+    use super::{gamut_map, max_chroma, GamutBoundary, GamutMapping, InGamut};
+
+    #[test]
+    fn in_gamut_is_unchanged() {
+        let color = Srgb::new(0.2f64, 0.5, 0.8);
+
+        for &mapping in &[GamutMapping::Clip, GamutMapping::ReduceChroma, GamutMapping::Project] {
+            let mapped: Srgb<f64> = gamut_map(color, mapping);
+            assert_relative_eq!(mapped, color, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn clip() {
+        let color = LinSrgb::new(1.5f64, -0.2, 0.5);
+        let mapped: LinSrgb<f64> = gamut_map(color, GamutMapping::Clip);
+
+        assert_relative_eq!(mapped, LinSrgb::new(1.0, 0.0, 0.5), epsilon = 0.000001);
+    }
+
+    #[test]
+    fn reduce_chroma_keeps_hue() {
+        let colors = [
+            Lch::new(80.0f64, 120.0, 140.0),
+            Lch::new(40.0f64, 130.0, 300.0),
+            Lch::new(60.0f64, 100.0, 30.0),
+        ];
+
+        for color in &colors {
+            let mapped: Srgb<f64> = gamut_map(*color, GamutMapping::ReduceChroma);
+            let lch = mapped.into_lch();
+
+            assert_relative_eq!(lch.l, color.l, epsilon = 2.0);
+            assert!(lch.chroma < color.chroma);
+
+            let clipped: Srgb<f64> = gamut_map(*color, GamutMapping::Clip);
+            let hue_shift = (lch.hue - color.hue).to_degrees().abs();
+            let clip_shift = (clipped.into_lch().hue - color.hue).to_degrees().abs();
+            assert!(hue_shift <= clip_shift + 0.5);
+        }
+    }
+
+    #[test]
+    fn reduce_chroma_is_near_the_boundary() {
+        let color = Lch::new(60.0f64, 100.0, 30.0);
+        let mapped: Srgb<f64> = gamut_map(color, GamutMapping::ReduceChroma);
+
+        let mut reduced = mapped.into_lch();
+        reduced.chroma = reduced.chroma + 5.0;
+        let outside: LinSrgb<f64> = reduced.into_rgb();
+        assert!(outside.red > 1.0 || outside.green < 0.0 || outside.blue < 0.0);
+        assert!(mapped.delta_e_ciede2000(color) < color.delta_e_ciede2000(Lch::new(60.0, 0.0, 30.0)));
+    }
+
+    #[test]
+    fn extremes() {
+        let white: Srgb<f64> = gamut_map(Lch::new(100.0f64, 50.0, 0.0), GamutMapping::ReduceChroma);
+        let black: Srgb<f64> = gamut_map(Lch::new(0.0f64, 50.0, 0.0), GamutMapping::ReduceChroma);
+
+        assert_relative_eq!(white, Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(black, Srgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn project_towards_gray() {
+        let color = Lch::new(95.0f64, 80.0, 100.0);
+        let mapped: Srgb<f64> = gamut_map(color, GamutMapping::Project);
+        let lch = mapped.into_lch();
+
+        assert!(lch.l < color.l);
+        assert!(lch.l > 50.0);
+        assert_relative_eq!(lch.hue.to_degrees(), 100.0, epsilon = 1.0);
+    }
+
+    #[test]
+    fn in_gamut() {
+        assert!(Srgb::new(0.2f64, 0.5, 0.8).is_in_gamut::<encoding::Srgb>());
+        assert!(Lch::new(50.0f64, 20.0, 120.0).is_in_gamut::<encoding::Srgb>());
+        assert!(!Lch::new(80.0f64, 120.0, 140.0).is_in_gamut::<encoding::Srgb>());
+        assert!(!LinSrgb::new(1.1f64, 0.5, 0.5).is_in_gamut::<encoding::Srgb>());
+
+        //Rec. 2020 is wider than sRGB.
+        let green = Rgb::<Linear<encoding::Rec2020>, f64>::new(0.1, 0.8, 0.1).into_lch();
+        assert!(!green.is_in_gamut::<encoding::Srgb>());
+        assert!(green.is_in_gamut::<encoding::Rec2020>());
+    }
+
+    #[test]
+    fn max_chroma_is_on_the_boundary() {
+        for &(l, hue) in &[(30.0f64, 20.0f64), (50.0, 140.0), (70.0, 250.0), (90.0, 100.0)] {
+            let hue = LabHue::from(hue);
+            let chroma = max_chroma::<encoding::Srgb, _, f64>(l, hue);
+
+            assert!(Lch::new(l, chroma * 0.999, hue).is_in_gamut::<encoding::Srgb>());
+            assert!(!Lch::new(l, chroma + 0.01, hue).is_in_gamut::<encoding::Srgb>());
+        }
+
+        //The sRGB red primary.
+        let red = Srgb::new(1.0f64, 0.0, 0.0).into_lch();
+        let chroma = max_chroma::<encoding::Srgb, _, f64>(red.l, red.hue);
+        assert_relative_eq!(chroma, red.chroma, epsilon = 0.01);
+
+        assert_eq!(max_chroma::<encoding::Srgb, _, f64>(101.0, LabHue::from(0.0)), 0.0);
+    }
+
+    #[test]
+    fn sampled_boundary() {
+        let boundary = GamutBoundary::<encoding::Srgb, f64>::new(51, 72);
+
+        for &(l, hue) in &[(30.0f64, 20.0f64), (55.0, 137.0), (72.0, 251.0)] {
+            let hue = LabHue::from(hue);
+            let exact = max_chroma::<encoding::Srgb, _, f64>(l, hue);
+            assert_relative_eq!(boundary.max_chroma(l, hue), exact, epsilon = exact * 0.05);
+        }
+
+        assert!(boundary.contains(Lch::new(50.0f64, 20.0, 120.0)));
+        assert!(!boundary.contains(Lch::new(80.0f64, 120.0, 140.0)));
+        assert!(boundary.chroma_margin(Srgb::new(0.5f64, 0.5, 0.5)) > 0.0);
+        assert_relative_eq!(boundary.max_chroma(100.0, LabHue::from(10.0)), 0.0, epsilon = 0.001);
+    }
+
+    #[test]
+    fn sampled_boundary_nan() {
+        let boundary = GamutBoundary::<encoding::Srgb, f64>::new(11, 12);
+        let nan = ::std::f64::NAN;
+
+        assert_eq!(boundary.max_chroma(nan, LabHue::from(10.0)), 0.0);
+        assert_eq!(boundary.max_chroma(50.0, LabHue::from(nan)), 0.0);
+        assert!(!boundary.contains(Lch::new(nan, 20.0, 120.0)));
+    }
+}
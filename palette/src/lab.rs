@@ -6,7 +6,7 @@ use std::marker::PhantomData;
 use {Alpha, LabHue, Lch, Xyz};
 use {Component, ComponentWise, GetHue, Limited, Mix, Pixel, Shade};
 use {cast, clamp};
-use white_point::{D65, WhitePoint};
+use white_point::{Dynamic, DynamicWhitePoint, D65, WhitePoint};
 use encoding::pixel::RawPixel;
 
 /// CIE L\*a\*b\* (CIELAB) with an alpha component. See the [`Laba`
@@ -104,6 +104,22 @@ where
     }
 }
 
+impl<T> Lab<Dynamic, T>
+where
+    T: Component + Float,
+{
+    ///Convert from Xyz, relative to a white point that is only known at
+    ///runtime. The white point of `color` is ignored, and the result is
+    ///tagged with the [`Dynamic`](white_point/struct.Dynamic.html) white
+    ///point.
+    pub fn from_xyz_with_white<Wp: WhitePoint>(
+        color: Xyz<Wp, T>,
+        white: &DynamicWhitePoint<T>,
+    ) -> Lab<Dynamic, T> {
+        from_relative_xyz(Xyz::with_wp(color.x, color.y, color.z) / white.get_xyz())
+    }
+}
+
 ///<span id="Laba"></span>[`Laba`](type.Laba.html) implementations.
 impl<T> Alpha<Lab<D65, T>, T>
 where
@@ -139,34 +155,42 @@ where
     Wp: WhitePoint,
 {
     fn from(color: Xyz<Wp, T>) -> Self {
-        let Xyz {
-            mut x,
-            mut y,
-            mut z,
-            ..
-        } = color / Wp::get_xyz();
-
-        fn convert<T: Component + Float>(c: T) -> T {
-            let epsilon: T = (cast::<T, _>(6.0 / 29.0)).powi(3);
-            let kappa: T = cast(841.0 / 108.0);
-            let delta: T = cast(4.0 / 29.0);
-            if c > epsilon {
-                c.powf(T::one() / cast(3.0))
-            } else {
-                (kappa * c) + delta
-            }
+        from_relative_xyz(color / Wp::get_xyz())
+    }
+}
+
+fn from_relative_xyz<Wp, T>(color: Xyz<Wp, T>) -> Lab<Wp, T>
+where
+    T: Component + Float,
+    Wp: WhitePoint,
+{
+    let Xyz {
+        mut x,
+        mut y,
+        mut z,
+        ..
+    } = color;
+
+    fn convert<T: Component + Float>(c: T) -> T {
+        let epsilon: T = (cast::<T, _>(6.0 / 29.0)).powi(3);
+        let kappa: T = cast(841.0 / 108.0);
+        let delta: T = cast(4.0 / 29.0);
+        if c > epsilon {
+            c.powf(T::one() / cast(3.0))
+        } else {
+            (kappa * c) + delta
         }
+    }
 
-        x = convert(x);
-        y = convert(y);
-        z = convert(z);
+    x = convert(x);
+    y = convert(y);
+    z = convert(z);
 
-        Lab {
-            l: ((y * cast(116.0)) - cast(16.0)),
-            a: ((x - y) * cast(500.0)),
-            b: ((y - z) * cast(200.0)),
-            white_point: PhantomData,
-        }
+    Lab {
+        l: ((y * cast(116.0)) - cast(16.0)),
+        a: ((x - y) * cast(500.0)),
+        b: ((y - z) * cast(200.0)),
+        white_point: PhantomData,
     }
 }
 
@@ -459,8 +483,25 @@ where
 #[cfg(test)]
 mod test {
     use super::Lab;
-    use LinSrgb;
-    use white_point::D65;
+    use {LinSrgb, Xyz};
+    use white_point::{DynamicWhitePoint, A, D65};
+
+    #[test]
+    fn dynamic_white_point() {
+        let color = Xyz::<A, f64>::with_wp(0.3, 0.2, 0.1);
+        let white = DynamicWhitePoint::from_static::<A>();
+
+        let lab = Lab::from_xyz_with_white(color, &white);
+        let expected = Lab::from(color);
+        assert_relative_eq!(lab, Lab::with_wp(expected.l, expected.a, expected.b));
+
+        let xyz = Xyz::from_lab_with_white(lab, &white);
+        assert_relative_eq!(xyz, Xyz::with_wp(color.x, color.y, color.z), epsilon = 0.000001);
+
+        let other = DynamicWhitePoint::new(0.9, 1.0, 0.8);
+        let lab = Lab::from_xyz_with_white(Xyz::<A, f64>::with_wp(0.9, 1.0, 0.8), &other);
+        assert_relative_eq!(lab, Lab::with_wp(100.0, 0.0, 0.0), epsilon = 0.000001);
+    }
 
     #[test]
     fn red() {
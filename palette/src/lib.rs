@@ -99,7 +99,8 @@ pub use gradient::Gradient;
 pub use alpha::Alpha;
 pub use blend::Blend;
 
-pub use rgb::{GammaSrgb, GammaSrgba, LinSrgb, LinSrgba, Srgb, Srgba};
+pub use rgb::{ExtendedSrgb, ExtendedSrgba, GammaSrgb, GammaSrgba, LinSrgb, LinSrgba, ScRgb, ScRgba,
+              Srgb, Srgba};
 pub use luma::{GammaLuma, GammaLumaa, LinLuma, LinLumaa, SrgbLuma, SrgbLumaa};
 pub use xyz::{Xyz, Xyza};
 pub use lab::{Lab, Laba};
@@ -111,6 +112,7 @@ pub use hwb::{Hwb, Hwba};
 
 pub use hues::{LabHue, RgbHue};
 pub use convert::{FromColor, IntoColor};
+pub use color_difference::ColorDifference;
 pub use matrix::Mat3;
 pub use encoding::pixel::Pixel;
 
@@ -290,8 +292,16 @@ mod hues;
 mod convert;
 mod equality;
 pub mod chromatic_adaptation;
+pub mod color_difference;
+pub mod contrast;
+pub mod gamut;
+pub mod surface;
+pub mod camera;
+pub mod cct;
+pub mod spectral;
 pub mod white_point;
 mod matrix;
+mod cie_data;
 pub mod encoding;
 
 macro_rules! make_color {
@@ -0,0 +1,83 @@
+//! Luma coefficients for non-constant luminance luma.
+//!
+//! Video and many legacy formats compute luma (Y′) as a weighted sum of the
+//! gamma encoded RGB components, instead of encoding the linear luminance.
+//! The weights are defined by each standard and don't necessarily match the
+//! primaries of the RGB values they are applied to.
+//!
+//! ```
+//! use palette::Srgb;
+//! use palette::luma::Luma;
+//! use palette::luma::coefficients::Rec601;
+//!
+//! let luma: Luma = Luma::from_encoded_rgb::<Rec601, _>(Srgb::new(1.0, 0.0, 0.0));
+//! assert_eq!(luma.luma, 0.299);
+//! ```
+
+use num_traits::Float;
+
+use cast;
+
+/// Weights for computing luma from gamma encoded RGB.
+///
+/// The weights are expected to add up to `1.0`.
+pub trait LumaCoefficients {
+    /// The weights of the red, green and blue components.
+    fn weights<T: Float>() -> (T, T, T);
+
+    /// Compute luma from gamma encoded red, green and blue components.
+    fn luma<T: Float>(red: T, green: T, blue: T) -> T {
+        let (kr, kg, kb) = Self::weights::<T>();
+        kr * red + kg * green + kb * blue
+    }
+}
+
+/// The Rec. 601 luma coefficients, 0.299, 0.587 and 0.114, as used in SDTV,
+/// JPEG and most legacy gray scale conversions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec601;
+
+impl LumaCoefficients for Rec601 {
+    fn weights<T: Float>() -> (T, T, T) {
+        (cast(0.299), cast(0.587), cast(0.114))
+    }
+}
+
+/// The Rec. 709 luma coefficients, 0.2126, 0.7152 and 0.0722, as used in
+/// HDTV.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec709;
+
+impl LumaCoefficients for Rec709 {
+    fn weights<T: Float>() -> (T, T, T) {
+        (cast(0.2126), cast(0.7152), cast(0.0722))
+    }
+}
+
+/// The Rec. 2020 non-constant luminance luma coefficients, 0.2627, 0.6780 and
+/// 0.0593, as used in UHDTV.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2020;
+
+impl LumaCoefficients for Rec2020 {
+    fn weights<T: Float>() -> (T, T, T) {
+        (cast(0.2627), cast(0.6780), cast(0.0593))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LumaCoefficients, Rec2020, Rec601, Rec709};
+
+    #[test]
+    fn weights_sum_to_one() {
+        fn sum<C: LumaCoefficients>() -> f64 {
+            let (r, g, b) = C::weights::<f64>();
+            r + g + b
+        }
+
+        assert_relative_eq!(sum::<Rec601>(), 1.0);
+        assert_relative_eq!(sum::<Rec709>(), 1.0);
+        assert_relative_eq!(sum::<Rec2020>(), 1.0);
+    }
+}
@@ -7,7 +7,8 @@ use std::marker::PhantomData;
 
 use {Alpha, Xyz, Yxy};
 use {Blend, Component, ComponentWise, FromColor, IntoColor, Limited, Mix, Pixel, Shade};
-use luma::LumaStandard;
+use luma::{LumaCoefficients, LumaStandard};
+use rgb::{Rgb, RgbStandard};
 use encoding::{Linear, Srgb, TransferFn};
 use encoding::linear::LinearFn;
 use encoding::pixel::RawPixel;
@@ -124,6 +125,32 @@ where
             color.luma,
         )))
     }
+
+    /// Compute non-constant luminance luma (Y′) from gamma encoded RGB, using
+    /// the luma coefficients `C`.
+    ///
+    /// The coefficients are applied directly to the encoded components, as in
+    /// video and legacy gray scale conversions, so the result is generally
+    /// not the same as converting via linear luminance. The RGB standard has
+    /// to use the same transfer function as the luma standard.
+    pub fn from_encoded_rgb<C, St>(color: Rgb<St, T>) -> Luma<S, T>
+    where
+        C: LumaCoefficients,
+        St: RgbStandard<TransferFn = S::TransferFn>,
+    {
+        Luma::new(C::luma(color.red, color.green, color.blue))
+    }
+
+    /// Convert the luma (Y′) to gray, gamma encoded RGB.
+    ///
+    /// This is the inverse of `from_encoded_rgb` for gray colors, regardless
+    /// of the luma coefficients.
+    pub fn into_encoded_rgb<St>(self) -> Rgb<St, T>
+    where
+        St: RgbStandard<TransferFn = S::TransferFn>,
+    {
+        Rgb::new(self.luma, self.luma, self.luma)
+    }
 }
 
 ///<span id="Lumaa"></span>[`Lumaa`](type.Lumaa.html) implementations.
@@ -539,7 +566,9 @@ where
 #[cfg(test)]
 mod test {
     use Luma;
+    use rgb::Rgb;
     use encoding::Srgb;
+    use luma::coefficients::{Rec601, Rec709};
 
     #[test]
     fn ranges() {
@@ -555,6 +584,29 @@ mod test {
 
     raw_pixel_conversion_tests!(Luma<Srgb>: luma);
 
+    #[test]
+    fn from_encoded_rgb() {
+        let rgb = Rgb::<Srgb, f64>::new(0.8, 0.4, 0.2);
+
+        let rec601 = Luma::<Srgb, f64>::from_encoded_rgb::<Rec601, _>(rgb);
+        let rec709 = Luma::<Srgb, f64>::from_encoded_rgb::<Rec709, _>(rgb);
+        assert_relative_eq!(rec601.luma, 0.299 * 0.8 + 0.587 * 0.4 + 0.114 * 0.2);
+        assert_relative_eq!(rec709.luma, 0.2126 * 0.8 + 0.7152 * 0.4 + 0.0722 * 0.2);
+
+        //The luminance path weights linear values, which gives another result.
+        let luminance: Luma<Srgb, f64> = rgb.into_linear().into();
+        assert!((luminance.luma - rec709.luma).abs() > 0.02);
+    }
+
+    #[test]
+    fn encoded_gray_round_trip() {
+        let gray = Luma::<Srgb, f64>::new(0.3);
+        let rgb: Rgb<Srgb, f64> = gray.into_encoded_rgb();
+
+        assert_relative_eq!(rgb, Rgb::new(0.3, 0.3, 0.3));
+        assert_relative_eq!(Luma::from_encoded_rgb::<Rec601, _>(rgb), gray);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize() {
@@ -1,11 +1,13 @@
 //! Luminance types.
 
 mod luma;
+pub mod coefficients;
 
 use white_point::{D65, WhitePoint};
 use encoding::{Gamma, Linear, Srgb, TransferFn};
 
 pub use self::luma::{Luma, Lumaa};
+pub use self::coefficients::LumaCoefficients;
 
 /// sRGB encoded luminance.
 pub type SrgbLuma<T = f32> = Luma<Srgb, T>;
@@ -28,6 +28,16 @@ pub type GammaSrgb<T = f32> = Rgb<::encoding::Gamma<::encoding::Srgb>, T>;
 /// Gamma 2.2 encoded sRGB with an alpha component.
 pub type GammaSrgba<T = f32> = Rgba<::encoding::Gamma<::encoding::Srgb>, T>;
 
+/// Extended range sRGB, where values outside `0.0` to `1.0` are meaningful.
+pub type ExtendedSrgb<T = f32> = Rgb<::encoding::ExtendedSrgb, T>;
+/// Extended range sRGB with an alpha component.
+pub type ExtendedSrgba<T = f32> = Rgba<::encoding::ExtendedSrgb, T>;
+
+/// Linear scRGB, which is linear sRGB with an extended range.
+pub type ScRgb<T = f32> = Rgb<::encoding::ScRgb, T>;
+/// Linear scRGB with an alpha component.
+pub type ScRgba<T = f32> = Rgba<::encoding::ScRgb, T>;
+
 ///An RGB space and a transfer function.
 pub trait RgbStandard {
     ///The RGB color space.
@@ -35,6 +45,13 @@ pub trait RgbStandard {
 
     ///The transfer function for the color components.
     type TransferFn: TransferFn;
+
+    ///The lowest and highest valid component values, as fractions of the
+    ///maximum intensity. This is what `Limited` checks and clamps to, and the
+    ///default is `0.0` to `1.0`.
+    fn component_range() -> (f64, f64) {
+        (0.0, 1.0)
+    }
 }
 
 impl<S: RgbSpace, T: TransferFn> RgbStandard for (S, T) {
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 use std::ops::{Add, Div, Mul, Sub};
 use std::any::TypeId;
 
-use num_traits::Float;
+use num_traits::{Float, NumCast};
 use approx::ApproxEq;
 
 use rgb::{RgbSpace, RgbStandard, TransferFn};
@@ -239,9 +239,10 @@ where
 {
     #[cfg_attr(rustfmt, rustfmt_skip)]
     fn is_valid(&self) -> bool {
-        self.red >= T::zero() && self.red <= T::max_intensity() &&
-        self.green >= T::zero() && self.green <= T::max_intensity() &&
-        self.blue >= T::zero() && self.blue <= T::max_intensity()
+        let (min, max) = component_limits::<S, T>();
+        self.red >= min && self.red <= max &&
+        self.green >= min && self.green <= max &&
+        self.blue >= min && self.blue <= max
     }
 
     fn clamp(&self) -> Rgb<S, T> {
@@ -251,10 +252,32 @@ where
     }
 
     fn clamp_self(&mut self) {
-        self.red = clamp(self.red, T::zero(), T::max_intensity());
-        self.green = clamp(self.green, T::zero(), T::max_intensity());
-        self.blue = clamp(self.blue, T::zero(), T::max_intensity());
-    }
+        let (min, max) = component_limits::<S, T>();
+        self.red = clamp(self.red, min, max);
+        self.green = clamp(self.green, min, max);
+        self.blue = clamp(self.blue, min, max);
+    }
+}
+
+///The component limits of the RGB standard, scaled to the component type.
+///Limits that can't be represented by the component type are replaced by the
+///zero to max intensity range.
+fn component_limits<S: RgbStandard, T: Component>() -> (T, T) {
+    let (min, max) = S::component_range();
+    let scale: f64 = cast(T::max_intensity());
+
+    let min = if min == 0.0 {
+        T::zero()
+    } else {
+        NumCast::from(min * scale).unwrap_or_else(T::zero)
+    };
+    let max = if max == 1.0 {
+        T::max_intensity()
+    } else {
+        NumCast::from(max * scale).unwrap_or_else(T::max_intensity)
+    };
+
+    (min, max)
 }
 
 impl<S, T> Mix for Rgb<S, T>
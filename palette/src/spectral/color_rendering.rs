@@ -0,0 +1,681 @@
+//!Color rendering metrics for light sources.
+//!
+//!These metrics compare how a set of reflective samples look under a test light
+//!source and under a reference illuminant with the same correlated color
+//!temperature.
+//!
+//! * [`cri`](fn.cri.html) computes the CIE 13.3 color rendering index, with the
+//!   special indices `Ri` and the general index `Ra`, in CIE 1964 U\*V\*W\*.
+//! * [`tm30`](fn.tm30.html) computes the IES TM-30-18 fidelity index `Rf` and
+//!   gamut index `Rg`, in CAM02-UCS.
+//!
+//!The sample reflectances are not included in this library. They are passed
+//!in order, as published: the 14 CIE 13.3 test color samples for `cri`, where
+//!the first 8 give `Ra` and the 9th gives `R9`, and the 99 color evaluation
+//!samples for `tm30`. Other sample sets can be used, but the results can then
+//!not be compared with published values.
+
+use std::f64::consts::PI;
+
+use num_traits::Float;
+
+use {cast, Component, Xyz};
+use white_point::{WhitePoint, E};
+use cct::{self, Method};
+use chromatic_adaptation::{self, Surround, TransformMatrix};
+use matrix::{matrix_inverse, multiply_3x3, Mat3};
+use super::{illuminant, Observer, Spd};
+
+///Colors in the CIE 1964 U\*V\*W\* color space, as used by the CIE color
+///rendering index.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uvw<T> {
+    ///The U\* chromaticity component.
+    pub u: T,
+    ///The V\* chromaticity component.
+    pub v: T,
+    ///The W\* lightness component, from about `-17` to `99` for colors that
+    ///are darker than the white.
+    pub w: T,
+}
+
+impl<T: Component + Float> Uvw<T> {
+    ///Convert from `Xyz`, relative to the tristimulus values of `white`.
+    pub fn from_xyz<Wp: WhitePoint>(color: Xyz<Wp, T>, white: Xyz<Wp, T>) -> Uvw<T> {
+        let (u, v) = uv(color.x, color.y, color.z);
+        let (white_u, white_v) = uv(white.x, white.y, white.z);
+        Uvw::from_uv(u, v, color.y / white.y, white_u, white_v)
+    }
+
+    fn from_uv(u: T, v: T, luminance: T, white_u: T, white_v: T) -> Uvw<T> {
+        let w = cast::<T, _>(25.0) * (luminance * cast(100.0)).cbrt() - cast(17.0);
+        Uvw {
+            u: cast::<T, _>(13.0) * w * (u - white_u),
+            v: cast::<T, _>(13.0) * w * (v - white_v),
+            w: w,
+        }
+    }
+
+    ///The Euclidean distance to another color, ΔE in U\*V\*W\*.
+    pub fn distance(&self, other: &Uvw<T>) -> T {
+        ((self.u - other.u).powi(2) + (self.v - other.v).powi(2) + (self.w - other.w).powi(2))
+            .sqrt()
+    }
+}
+
+///Colors in the CAM02-UCS uniform color space, as used by IES TM-30.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cam02Ucs<T> {
+    ///The J' lightness component.
+    pub j: T,
+    ///The a' red-green component.
+    pub a: T,
+    ///The b' yellow-blue component.
+    pub b: T,
+}
+
+impl<T: Component + Float> Cam02Ucs<T> {
+    ///Convert from `Xyz`, relative to the tristimulus values of `white`, with
+    ///the viewing conditions of TM-30: an adapting luminance of 100 cd/m², a
+    ///background luminance factor of 20, an average surround and complete
+    ///adaptation.
+    pub fn from_xyz<Wp: WhitePoint>(color: Xyz<Wp, T>, white: Xyz<Wp, T>) -> Cam02Ucs<T> {
+        let scale = cast::<T, _>(100.0) / white.y;
+        let (j, m, h) = ciecam02(
+            [color.x * scale, color.y * scale, color.z * scale],
+            [white.x * scale, white.y * scale, white.z * scale],
+            cast(100.0),
+            cast(20.0),
+            T::one(),
+        );
+
+        let m = (T::one() + cast::<T, _>(0.0228) * m).ln() / cast(0.0228);
+        Cam02Ucs {
+            j: cast::<T, _>(1.7) * j / (T::one() + cast::<T, _>(0.007) * j),
+            a: m * h.cos(),
+            b: m * h.sin(),
+        }
+    }
+
+    ///The hue angle, in degrees, from `0.0` to `360.0`.
+    pub fn hue(&self) -> T {
+        let hue = self.b.atan2(self.a).to_degrees();
+        if hue < T::zero() {
+            hue + cast(360.0)
+        } else {
+            hue
+        }
+    }
+
+    ///The chroma, M'.
+    pub fn chroma(&self) -> T {
+        self.a.hypot(self.b)
+    }
+
+    ///The Euclidean distance to another color, ΔE'.
+    pub fn distance(&self, other: &Cam02Ucs<T>) -> T {
+        ((self.j - other.j).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+///The CIE 13.3 color rendering of a light source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cri<T> {
+    ///The correlated color temperature of the light source, in K.
+    pub cct: T,
+    ///The distance from the Planckian locus. The result is only considered
+    ///valid if its magnitude is less than `0.0054`.
+    pub duv: T,
+    ///The general color rendering index, the mean of `R1` to `R8`.
+    pub ra: T,
+    ///The special color rendering index for the 9th sample, which is a
+    ///saturated red in the CIE 13.3 set.
+    pub r9: Option<T>,
+    ///The details for each sample, in the order they were passed.
+    pub samples: Vec<CriSample<T>>,
+}
+
+///The CIE 13.3 color rendering of a single sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CriSample<T> {
+    ///The color of the sample under the reference illuminant.
+    pub reference: Uvw<T>,
+    ///The color of the sample under the light source, after chromatic
+    ///adaptation to the reference illuminant.
+    pub test: Uvw<T>,
+    ///The color difference between `reference` and `test`.
+    pub delta_e: T,
+    ///The special color rendering index, `Ri`.
+    pub index: T,
+}
+
+///Compute the CIE 13.3 color rendering index of `source` for the sample
+///reflectances `samples`.
+///
+///The reference illuminant is a Planckian radiator below 5000 K and CIE
+///daylight from 5000 K, at the correlated color temperature of `source`.
+///Returns `None` if there are less than 8 samples or if the correlated color
+///temperature can't be estimated.
+pub fn cri<T: Component + Float>(source: &Spd<T>, samples: &[Spd<T>]) -> Option<Cri<T>> {
+    if samples.len() < 8 {
+        return None;
+    }
+
+    let observer = Observer::Cie1931;
+    let white: Xyz<E, T> = source.white_point(observer).get_xyz();
+    let temperature = cct::estimate(white, Method::LocusSearch)?;
+
+    let reference = if temperature.cct < cast(5000.0) {
+        illuminant::blackbody(temperature.cct)
+    } else {
+        illuminant::d(temperature.cct)
+    };
+    let reference_white: Xyz<E, T> = reference.white_point(observer).get_xyz();
+
+    let (test_u, test_v) = uv(white.x, white.y, white.z);
+    let (reference_u, reference_v) = uv(reference_white.x, reference_white.y, reference_white.z);
+    let (test_c, test_d) = von_kries_cd(test_u, test_v);
+    let (reference_c, reference_d) = von_kries_cd(reference_u, reference_v);
+
+    let samples: Vec<_> = samples
+        .iter()
+        .map(|sample| {
+            let color: Xyz<E, T> = sample.reflective_xyz(&reference, observer);
+            let (u, v) = uv(color.x, color.y, color.z);
+            let reference_color = Uvw::from_uv(u, v, color.y, reference_u, reference_v);
+
+            //Adapt the sample under the test source to the reference white
+            let color: Xyz<E, T> = sample.reflective_xyz(source, observer);
+            let (u, v) = uv(color.x, color.y, color.z);
+            let (c, d) = von_kries_cd(u, v);
+            let c = reference_c / test_c * c;
+            let d = reference_d / test_d * d;
+            let denominator = cast::<T, _>(16.518) + cast::<T, _>(1.481) * c - d;
+            let adapted_u = (cast::<T, _>(10.872) + cast::<T, _>(0.404) * c
+                - cast::<T, _>(4.0) * d) / denominator;
+            let adapted_v = cast::<T, _>(5.520) / denominator;
+            let test_color = Uvw::from_uv(adapted_u, adapted_v, color.y, reference_u, reference_v);
+
+            let delta_e = reference_color.distance(&test_color);
+            CriSample {
+                reference: reference_color,
+                test: test_color,
+                delta_e: delta_e,
+                index: cast::<T, _>(100.0) - cast::<T, _>(4.6) * delta_e,
+            }
+        })
+        .collect();
+
+    let ra = samples[..8]
+        .iter()
+        .fold(T::zero(), |sum, sample| sum + sample.index) / cast(8.0);
+
+    Some(Cri {
+        cct: temperature.cct,
+        duv: temperature.duv,
+        ra: ra,
+        r9: samples.get(8).map(|sample| sample.index),
+        samples: samples,
+    })
+}
+
+///The IES TM-30 color rendering of a light source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tm30<T> {
+    ///The correlated color temperature of the light source, in K.
+    pub cct: T,
+    ///The distance from the Planckian locus.
+    pub duv: T,
+    ///The fidelity index, `Rf`, from `0` to `100`.
+    pub rf: T,
+    ///The gamut index, `Rg`, which is `100` when the average chroma is the same
+    ///as for the reference illuminant.
+    pub rg: T,
+    ///The details for each sample, in the order they were passed.
+    pub samples: Vec<Tm30Sample<T>>,
+    ///The details for each of the 16 hue bins, starting at a hue angle of 0°.
+    pub hue_bins: Vec<HueBin<T>>,
+}
+
+///The IES TM-30 color rendering of a single sample.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tm30Sample<T> {
+    ///The color of the sample under the reference illuminant.
+    pub reference: Cam02Ucs<T>,
+    ///The color of the sample under the light source.
+    pub test: Cam02Ucs<T>,
+    ///The color difference between `reference` and `test`.
+    pub delta_e: T,
+    ///The fidelity index for this sample, `Rf,i`.
+    pub fidelity: T,
+    ///The hue bin of the sample, from `0` to `15`, based on its reference
+    ///hue.
+    pub hue_bin: usize,
+}
+
+///The IES TM-30 color rendering of the samples in one hue bin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HueBin<T> {
+    ///The average color of the samples under the reference illuminant.
+    pub reference: Cam02Ucs<T>,
+    ///The average color of the samples under the light source.
+    pub test: Cam02Ucs<T>,
+    ///The fidelity index for the hue bin, `Rf,hj`.
+    pub fidelity: T,
+    ///The relative change in chroma, `Rcs,hj`.
+    pub chroma_shift: T,
+    ///The change in hue angle, `Rhs,hj`, in radians.
+    pub hue_shift: T,
+}
+
+///Compute the IES TM-30-18 color rendering of `source` for the sample
+///reflectances `samples`.
+///
+///The reference illuminant is a Planckian radiator below 4000 K, CIE daylight
+///above 5000 K and a mix of the two in between, at the correlated color
+///temperature of `source`. The correlated color temperature is estimated with
+///Ohno's method, as specified by TM-30-18. Returns `None` if it can't be
+///estimated or if a hue bin doesn't contain any sample.
+pub fn tm30<T: Component + Float>(source: &Spd<T>, samples: &[Spd<T>]) -> Option<Tm30<T>> {
+    let white: Xyz<E, T> = source.white_point(Observer::Cie1931).get_xyz();
+    let temperature = cct::estimate(white, Method::Ohno)?;
+
+    let observer = Observer::Cie1964;
+    let reference = tm30_reference(temperature.cct, observer);
+    let test_white: Xyz<E, T> = source.white_point(observer).get_xyz();
+    let reference_white: Xyz<E, T> = reference.white_point(observer).get_xyz();
+
+    let samples: Vec<_> = samples
+        .iter()
+        .map(|sample| {
+            let reference_color = Cam02Ucs::from_xyz(
+                sample.reflective_xyz::<E>(&reference, observer),
+                reference_white,
+            );
+            let test_color =
+                Cam02Ucs::from_xyz(sample.reflective_xyz::<E>(source, observer), test_white);
+            let delta_e = reference_color.distance(&test_color);
+            let bin = (reference_color.hue() / cast(22.5)).to_usize().unwrap_or(0);
+
+            Tm30Sample {
+                reference: reference_color,
+                test: test_color,
+                delta_e: delta_e,
+                fidelity: fidelity(delta_e),
+                hue_bin: bin.min(15),
+            }
+        })
+        .collect();
+
+    let mut hue_bins = Vec::with_capacity(16);
+    for bin in 0..16 {
+        let in_bin: Vec<_> = samples.iter().filter(|sample| sample.hue_bin == bin).collect();
+        if in_bin.is_empty() {
+            return None;
+        }
+
+        let count: T = cast(in_bin.len() as f64);
+        let average = |color: &dyn Fn(&Tm30Sample<T>) -> Cam02Ucs<T>| {
+            let sum = in_bin.iter().fold((T::zero(), T::zero(), T::zero()), |sum, sample| {
+                let color = color(sample);
+                (sum.0 + color.j, sum.1 + color.a, sum.2 + color.b)
+            });
+            Cam02Ucs {
+                j: sum.0 / count,
+                a: sum.1 / count,
+                b: sum.2 / count,
+            }
+        };
+        let reference_color = average(&|sample| sample.reference);
+        let test_color = average(&|sample| sample.test);
+        let delta_e = in_bin
+            .iter()
+            .fold(T::zero(), |sum, sample| sum + sample.delta_e) / count;
+
+        let reference_hue = reference_color.b.atan2(reference_color.a);
+        let mut hue_shift = test_color.b.atan2(test_color.a) - reference_hue;
+        if hue_shift > cast(PI) {
+            hue_shift = hue_shift - cast::<T, _>(2.0 * PI);
+        } else if hue_shift < -cast::<T, _>(PI) {
+            hue_shift = hue_shift + cast::<T, _>(2.0 * PI);
+        }
+
+        hue_bins.push(HueBin {
+            reference: reference_color,
+            test: test_color,
+            fidelity: fidelity(delta_e),
+            chroma_shift: (test_color.chroma() - reference_color.chroma())
+                / reference_color.chroma(),
+            hue_shift: hue_shift,
+        });
+    }
+
+    let delta_e = samples
+        .iter()
+        .fold(T::zero(), |sum, sample| sum + sample.delta_e) / cast(samples.len() as f64);
+    let area = |color: &dyn Fn(&HueBin<T>) -> Cam02Ucs<T>| {
+        let doubled = (0..16).fold(T::zero(), |sum, bin| {
+            let current = color(&hue_bins[bin]);
+            let next = color(&hue_bins[(bin + 1) % 16]);
+            sum + current.a * next.b - next.a * current.b
+        });
+        doubled / cast(2.0)
+    };
+    let rg = area(&|bin| bin.test) / area(&|bin| bin.reference) * cast(100.0);
+
+    Some(Tm30 {
+        cct: temperature.cct,
+        duv: temperature.duv,
+        rf: fidelity(delta_e),
+        rg: rg,
+        samples: samples,
+        hue_bins: hue_bins,
+    })
+}
+
+///The TM-30 reference illuminant, normalized to Y = 100 for `observer`.
+fn tm30_reference<T: Component + Float>(cct: T, observer: Observer) -> Spd<T> {
+    let normalized = |spd: Spd<T>| {
+        let white: Xyz<E, T> = spd.emissive_xyz(observer);
+        spd.scale(cast::<T, _>(100.0) / white.y)
+    };
+
+    let low: T = cast(4000.0);
+    let high: T = cast(5000.0);
+
+    if cct < low {
+        normalized(illuminant::blackbody(cct))
+    } else if cct > high {
+        normalized(illuminant::d(cct))
+    } else {
+        let planckian = normalized(illuminant::blackbody(cct));
+        let daylight = normalized(illuminant::d(cct));
+        let weight = (high - cct) / (high - low);
+        Spd::from_fn(cast(380.0), cast(780.0), cast(5.0), |wavelength| {
+            planckian.at(wavelength) * weight + daylight.at(wavelength) * (T::one() - weight)
+        })
+    }
+}
+
+///The TM-30-18 conversion from a color difference to a fidelity index.
+fn fidelity<T: Float>(delta_e: T) -> T {
+    cast::<T, _>(10.0)
+        * (((cast::<T, _>(100.0) - cast::<T, _>(6.73) * delta_e) / cast(10.0)).exp() + T::one())
+            .ln()
+}
+
+///The CIE 1960 UCS chromaticity coordinates.
+fn uv<T: Float>(x: T, y: T, z: T) -> (T, T) {
+    let denominator = x + y * cast(15.0) + z * cast(3.0);
+    (
+        x * cast(4.0) / denominator,
+        y * cast(6.0) / denominator,
+    )
+}
+
+///The `c` and `d` coordinates of the von Kries transform in CIE 13.3.
+fn von_kries_cd<T: Float>(u: T, v: T) -> (T, T) {
+    (
+        (cast::<T, _>(4.0) - u - cast::<T, _>(10.0) * v) / v,
+        (cast::<T, _>(1.708) * v + cast(0.404) - cast::<T, _>(1.481) * u) / v,
+    )
+}
+
+///The CIECAM02 lightness J, colorfulness M and hue angle h, in radians, for an
+///average surround. The tristimulus values are scaled to make Y = 100 for the
+///white.
+fn ciecam02<T: Component + Float>(
+    xyz: [T; 3],
+    white: [T; 3],
+    adapting_luminance: T,
+    background_luminance: T,
+    degree_of_adaptation: T,
+) -> (T, T, T) {
+    let cat02 = TransformMatrix::<E, E, T>::get_cone_response(&chromatic_adaptation::Method::Cat02);
+    let surround: T = Surround::Average.factor();
+    let c: T = cast(0.69);
+
+    let k = T::one() / (cast::<T, _>(5.0) * adapting_luminance + T::one());
+    let k4 = k.powi(4);
+    let la5 = cast::<T, _>(5.0) * adapting_luminance;
+    let fl = cast::<T, _>(0.2) * k4 * la5
+        + cast::<T, _>(0.1) * (T::one() - k4).powi(2) * la5.cbrt();
+    let n = background_luminance / white[1];
+    let nbb = cast::<T, _>(0.725) * (T::one() / n).powf(cast(0.2));
+    let z = cast::<T, _>(1.48) + n.sqrt();
+
+    let white_cone = multiply(&cat02.ma, white);
+    let gains = [
+        degree_of_adaptation * white[1] / white_cone[0] + T::one() - degree_of_adaptation,
+        degree_of_adaptation * white[1] / white_cone[1] + T::one() - degree_of_adaptation,
+        degree_of_adaptation * white[1] / white_cone[2] + T::one() - degree_of_adaptation,
+    ];
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let hpe: Mat3<T> = [
+        cast(0.38971), cast(0.68898), cast(-0.07868),
+        cast(-0.22981), cast(1.18340), cast(0.04641),
+        T::zero(), T::zero(), T::one(),
+    ];
+    let to_hpe = multiply_3x3(&hpe, &matrix_inverse(&cat02.ma));
+
+    let compress = |value: T| {
+        let scaled = (fl * value.abs() / cast(100.0)).powf(cast(0.42));
+        value.signum() * cast::<T, _>(400.0) * scaled / (cast::<T, _>(27.13) + scaled)
+            + cast(0.1)
+    };
+    let responses = |xyz: [T; 3]| {
+        let cone = multiply(&cat02.ma, xyz);
+        let adapted = [cone[0] * gains[0], cone[1] * gains[1], cone[2] * gains[2]];
+        let hpe = multiply(&to_hpe, adapted);
+        [compress(hpe[0]), compress(hpe[1]), compress(hpe[2])]
+    };
+    let achromatic = |rgb: [T; 3]| {
+        (rgb[0] * cast(2.0) + rgb[1] + rgb[2] / cast(20.0) - cast(0.305)) * nbb
+    };
+
+    let rgb = responses(xyz);
+    let white_rgb = responses(white);
+
+    let a = rgb[0] - rgb[1] * cast(12.0 / 11.0) + rgb[2] / cast(11.0);
+    let b = (rgb[0] + rgb[1] - rgb[2] * cast(2.0)) / cast(9.0);
+    let mut hue = b.atan2(a);
+    if hue < T::zero() {
+        hue = hue + cast::<T, _>(2.0 * PI);
+    }
+
+    let j = cast::<T, _>(100.0) * (achromatic(rgb) / achromatic(white_rgb)).powf(c * z);
+    let eccentricity = ((hue + cast(2.0)).cos() + cast(3.8)) / cast(4.0);
+    let t = cast::<T, _>(50000.0 / 13.0) * surround * nbb * eccentricity * a.hypot(b)
+        / (rgb[0] + rgb[1] + rgb[2] * cast(21.0 / 20.0));
+    let chroma = t.powf(cast(0.9)) * (j / cast(100.0)).sqrt()
+        * (cast::<T, _>(1.64) - cast::<T, _>(0.29).powf(n)).powf(cast(0.73));
+
+    (j, chroma * fl.powf(cast(0.25)), hue)
+}
+
+fn multiply<T: Float>(matrix: &Mat3<T>, vector: [T; 3]) -> [T; 3] {
+    [
+        matrix[0] * vector[0] + matrix[1] * vector[1] + matrix[2] * vector[2],
+        matrix[3] * vector[0] + matrix[4] * vector[1] + matrix[5] * vector[2],
+        matrix[6] * vector[0] + matrix[7] * vector[1] + matrix[8] * vector[2],
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use Xyz;
+    use white_point::D65;
+    use spectral::{illuminant, Observer, Spd};
+    use spectral::illuminant::Fluorescent;
+    use super::{cri, tm30, tm30_reference, Cam02Ucs, Uvw};
+
+    ///Smooth reflectances with peaks and notches spread over the visible
+    ///range, to cover every hue.
+    fn samples(count: usize) -> Vec<Spd<f64>> {
+        (0..count)
+            .map(|i| {
+                let center = 400.0 + 300.0 * (i / 2) as f64 / (count / 2) as f64;
+                Spd::from_fn(380.0, 780.0, 5.0, |wavelength| {
+                    let band = 0.7 * (-((wavelength - center) / 40.0).powi(2)).exp();
+                    if i % 2 == 0 {
+                        0.1 + band
+                    } else {
+                        0.8 - band
+                    }
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ciecam02() {
+        let (j, m, h) = super::ciecam02(
+            [19.01, 20.0, 21.78],
+            [95.05, 100.0, 108.88f64],
+            318.31,
+            20.0,
+            0.9944535,
+        );
+
+        assert_relative_eq!(j, 41.731091, epsilon = 0.001);
+        assert_relative_eq!(m, 0.108842, epsilon = 0.001);
+        assert_relative_eq!(h.to_degrees(), 219.048432, epsilon = 0.1);
+    }
+
+    #[test]
+    fn white_is_neutral() {
+        let white = Xyz::<D65, f64>::with_wp(0.95047, 1.0, 1.08883);
+
+        let uvw = Uvw::from_xyz(white, white);
+        assert_relative_eq!(uvw.u, 0.0);
+        assert_relative_eq!(uvw.v, 0.0);
+        assert_relative_eq!(uvw.w, 25.0 * 100.0f64.cbrt() - 17.0);
+
+        let ucs = Cam02Ucs::from_xyz(white, white);
+        assert_relative_eq!(ucs.a, 0.0, epsilon = 0.01);
+        assert_relative_eq!(ucs.b, 0.0, epsilon = 0.01);
+        assert_relative_eq!(ucs.j, 170.0 / 1.7, epsilon = 0.001);
+    }
+
+    #[test]
+    fn cri_of_reference() {
+        let result = cri(&illuminant::blackbody(2856.0), &samples(14)).unwrap();
+        assert_relative_eq!(result.cct, 2856.0, epsilon = 1.0);
+        assert_relative_eq!(result.ra, 100.0, epsilon = 0.1);
+        assert_relative_eq!(result.r9.unwrap(), 100.0, epsilon = 0.1);
+        assert_eq!(result.samples.len(), 14);
+
+        let result = cri(&illuminant::d65(), &samples(8)).unwrap();
+        assert_relative_eq!(result.ra, 100.0, epsilon = 0.1);
+        assert_eq!(result.r9, None);
+    }
+
+    #[test]
+    fn cri_of_narrowband() {
+        let result = cri(
+            &illuminant::fluorescent(illuminant::Fluorescent::F11),
+            &samples(14),
+        ).unwrap();
+        assert_relative_eq!(result.cct, 4000.0, epsilon = 50.0);
+        assert!(result.ra < 95.0);
+    }
+
+    #[test]
+    fn too_few_samples() {
+        assert_eq!(cri(&illuminant::d65(), &samples(7)), None);
+        assert_eq!(tm30(&illuminant::d65(), &samples(8)), None);
+    }
+
+    #[test]
+    fn tm30_of_reference() {
+        let result = tm30(&illuminant::blackbody(3000.0), &samples(99)).unwrap();
+        assert_relative_eq!(result.rf, 100.0, epsilon = 0.5);
+        assert_relative_eq!(result.rg, 100.0, epsilon = 0.5);
+        assert_eq!(result.hue_bins.len(), 16);
+
+        let result = tm30(
+            &illuminant::fluorescent(illuminant::Fluorescent::F11),
+            &samples(99),
+        ).unwrap();
+        assert!(result.rf < 95.0);
+    }
+
+    #[test]
+    fn cri_adapts_white() {
+        //A perfect white under the test source must end up exactly on the
+        //reference white after the von Kries transform.
+        let white = Spd::from_fn(380.0, 780.0, 5.0, |_| 1.0);
+        let result = cri(&illuminant::fluorescent(Fluorescent::F2), &vec![white; 8]).unwrap();
+        for sample in &result.samples {
+            assert_relative_eq!(sample.test.u, 0.0, epsilon = 1e-10);
+            assert_relative_eq!(sample.test.v, 0.0, epsilon = 1e-10);
+            assert_relative_eq!(sample.test.w, sample.reference.w, epsilon = 1e-10);
+            assert_relative_eq!(sample.index, 100.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn cri_reference_switch() {
+        let below = cri(&illuminant::blackbody(4990.0), &samples(8)).unwrap();
+        assert_relative_eq!(below.ra, 100.0, epsilon = 0.01);
+        let below = cri(&illuminant::d(4990.0), &samples(8)).unwrap();
+        assert!(below.ra < 97.0);
+
+        let above = cri(&illuminant::blackbody(5010.0), &samples(8)).unwrap();
+        assert!(above.ra < 97.0);
+        let above = cri(&illuminant::d(5010.0), &samples(8)).unwrap();
+        assert_relative_eq!(above.ra, 100.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn tm30_reference_mix() {
+        let observer = Observer::Cie1964;
+        for &(cct, mixed) in &[(4000.0, 3999.9), (5000.0, 5000.1)] {
+            let pure = tm30_reference(cct, observer);
+            let mixed = tm30_reference(mixed, observer);
+            for wavelength in (76..157).map(|i| i as f64 * 5.0) {
+                assert_relative_eq!(pure.at(wavelength), mixed.at(wavelength), epsilon = 0.01);
+            }
+        }
+
+        let result = tm30(&tm30_reference(4500.0, observer), &samples(99)).unwrap();
+        assert_relative_eq!(result.rf, 100.0, epsilon = 0.01);
+        assert_relative_eq!(result.rg, 100.0, epsilon = 0.01);
+        let result = tm30(&illuminant::blackbody(4500.0), &samples(99)).unwrap();
+        assert!(result.rf < 99.0);
+    }
+
+    #[test]
+    fn tm30_hue_bins() {
+        let result = tm30(&illuminant::fluorescent(Fluorescent::F2), &samples(99)).unwrap();
+
+        let mut areas = (0.0, 0.0);
+        for (index, bin) in result.hue_bins.iter().enumerate() {
+            let in_bin: Vec<_> = result
+                .samples
+                .iter()
+                .filter(|sample| sample.hue_bin == index)
+                .collect();
+            let count = in_bin.len() as f64;
+            for sample in &in_bin {
+                let hue = sample.reference.hue();
+                assert!(hue >= index as f64 * 22.5 && hue < (index + 1) as f64 * 22.5);
+            }
+
+            let reference_a = in_bin.iter().map(|sample| sample.reference.a).sum::<f64>();
+            let test_b = in_bin.iter().map(|sample| sample.test.b).sum::<f64>();
+            assert_relative_eq!(bin.reference.a, reference_a / count, epsilon = 1e-10);
+            assert_relative_eq!(bin.test.b, test_b / count, epsilon = 1e-10);
+
+            let next = &result.hue_bins[(index + 1) % 16];
+            areas.0 += bin.test.a * next.test.b - next.test.a * bin.test.b;
+            areas.1 += bin.reference.a * next.reference.b - next.reference.a * bin.reference.b;
+        }
+
+        assert_eq!(result.samples.len(), 99);
+        assert_relative_eq!(result.rg, 100.0 * areas.0 / areas.1, epsilon = 1e-10);
+        assert!(result.rf < 95.0);
+    }
+}
@@ -0,0 +1,130 @@
+//!Relative spectral power distributions of the CIE standard illuminants.
+//!
+//!The distributions are normalized to about `100.0` at 560 nm.
+
+use num_traits::Float;
+
+use {cast, Component};
+use cie_data::{self, DAYLIGHT_COMPONENTS, DAYLIGHT_INTERVAL, DAYLIGHT_START, FLUORESCENT,
+               FLUORESCENT_INTERVAL, FLUORESCENT_START};
+use super::Spd;
+
+///A CIE fluorescent illuminant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fluorescent {
+    ///F1, a standard halophosphate lamp with a CCT of 6430 K.
+    F1,
+    ///F2, a standard halophosphate lamp with a CCT of 4230 K.
+    F2,
+    ///F3, a standard halophosphate lamp with a CCT of 3450 K.
+    F3,
+    ///F4, a standard halophosphate lamp with a CCT of 2940 K.
+    F4,
+    ///F5, a standard halophosphate lamp with a CCT of 6350 K.
+    F5,
+    ///F6, a standard halophosphate lamp with a CCT of 4150 K.
+    F6,
+    ///F7, a broadband lamp with a CCT of 6500 K.
+    F7,
+    ///F8, a broadband lamp with a CCT of 5000 K.
+    F8,
+    ///F9, a broadband lamp with a CCT of 4150 K.
+    F9,
+    ///F10, a narrowband lamp with a CCT of 5000 K.
+    F10,
+    ///F11, a narrowband lamp with a CCT of 4000 K.
+    F11,
+    ///F12, a narrowband lamp with a CCT of 3000 K.
+    F12,
+}
+
+///CIE standard illuminant A, from 300 nm to 830 nm in steps of 5 nm.
+pub fn a<T: Component + Float>() -> Spd<T> {
+    blackbody(cast(2856.0))
+}
+
+///A black body radiator at the temperature `temperature`, in K, from 300 nm to
+///830 nm in steps of 5 nm.
+pub fn blackbody<T: Component + Float>(temperature: T) -> Spd<T> {
+    Spd::from_fn(cast(300.0), cast(830.0), cast(5.0), |wavelength| {
+        cie_data::planck(temperature, wavelength)
+    })
+}
+
+///CIE daylight with the correlated color temperature `cct`, in K, from 300 nm
+///to 830 nm in steps of 10 nm.
+///
+///The CIE daylight model is defined from 4000 K to 25000 K.
+pub fn d<T: Component + Float>(cct: T) -> Spd<T> {
+    let end = DAYLIGHT_START + DAYLIGHT_INTERVAL * (DAYLIGHT_COMPONENTS.len() - 1) as f64;
+
+    Spd::from_fn(
+        cast(DAYLIGHT_START),
+        cast(end),
+        cast(DAYLIGHT_INTERVAL),
+        |wavelength| cie_data::daylight(cct, wavelength),
+    )
+}
+
+///CIE standard illuminant D50, which is CIE daylight at 5003 K.
+pub fn d50<T: Component + Float>() -> Spd<T> {
+    d(cast(5003.0))
+}
+
+///CIE standard illuminant D65, which is CIE daylight at 6504 K.
+pub fn d65<T: Component + Float>() -> Spd<T> {
+    d(cast(6504.0))
+}
+
+///CIE standard illuminant E, the equal energy radiator, from 300 nm to 830 nm
+///in steps of 5 nm.
+pub fn e<T: Component + Float>() -> Spd<T> {
+    Spd::from_fn(cast(300.0), cast(830.0), cast(5.0), |_| cast(100.0))
+}
+
+///A CIE fluorescent illuminant, from 380 nm to 780 nm in steps of 5 nm.
+pub fn fluorescent<T: Component + Float>(lamp: Fluorescent) -> Spd<T> {
+    let values = FLUORESCENT[lamp as usize]
+        .iter()
+        .map(|&value| cast(value))
+        .collect();
+
+    Spd::new(cast(FLUORESCENT_START), cast(FLUORESCENT_INTERVAL), values)
+}
+
+#[cfg(test)]
+mod test {
+    use white_point::{F11, F11Degree10, F2, F2Degree10, F7, F7Degree10, WhitePoint, A};
+    use spectral::Observer;
+    use super::{a, blackbody, d, e, fluorescent, Fluorescent};
+
+    #[test]
+    fn normalization() {
+        assert_relative_eq!(a::<f64>().at(560.0), 100.0);
+        assert_relative_eq!(blackbody::<f64>(5000.0).at(560.0), 100.0);
+        assert_relative_eq!(d::<f64>(5000.0).at(560.0), 100.0, epsilon = 0.5);
+        assert_relative_eq!(e::<f64>().at(560.0), 100.0);
+        assert_eq!(fluorescent::<f64>(Fluorescent::F1).at(380.0), 1.87);
+        assert_eq!(fluorescent::<f64>(Fluorescent::F12).at(780.0), 0.05);
+    }
+
+    #[test]
+    fn illuminant_a() {
+        assert!(a::<f64>().white_point(Observer::Cie1931).is_close_to::<A>(0.001));
+    }
+
+    #[test]
+    fn fluorescent_white_points() {
+        fn check<Wp: WhitePoint>(lamp: Fluorescent, observer: Observer) {
+            let white = fluorescent::<f64>(lamp).white_point(observer);
+            assert!(white.is_close_to::<Wp>(0.002), "{:?} {:?}", lamp, white);
+        }
+
+        check::<F2>(Fluorescent::F2, Observer::Cie1931);
+        check::<F7>(Fluorescent::F7, Observer::Cie1931);
+        check::<F11>(Fluorescent::F11, Observer::Cie1931);
+        check::<F2Degree10>(Fluorescent::F2, Observer::Cie1964);
+        check::<F7Degree10>(Fluorescent::F7, Observer::Cie1964);
+        check::<F11Degree10>(Fluorescent::F11, Observer::Cie1964);
+    }
+}
@@ -0,0 +1,230 @@
+//!Metamerism indices for pairs of reflectance spectra.
+//!
+//!Two surfaces with different spectral reflectances can match under one
+//!illuminant, or for one observer, but not another. The special metamerism
+//!indices in CIE 15 measure how much such a pair stops matching when the
+//!illuminant or the observer is changed.
+//!
+//!The samples rarely match exactly under the reference conditions, so the
+//!tristimulus values of the second sample are corrected multiplicatively to
+//!match the first one, as recommended by the CIE, before they are compared
+//!under the test conditions.
+//!
+//!```
+//!use palette::spectral::{illuminant, Observer, Spd};
+//!use palette::spectral::metamerism::illuminant_metamerism;
+//!
+//!let first = Spd::new(400.0, 100.0, vec![0.2, 0.5, 0.8, 0.6]);
+//!let second = Spd::new(400.0, 100.0, vec![0.3, 0.4, 0.9, 0.5]);
+//!
+//!let metamerism = illuminant_metamerism(
+//!    &first,
+//!    &second,
+//!    &illuminant::d65(),
+//!    &illuminant::a(),
+//!    Observer::Cie1931,
+//!);
+//!assert!(metamerism.index > 0.0);
+//!```
+
+use num_traits::Float;
+
+use {Component, Lab, Xyz};
+use white_point::E;
+use color_difference::cie76;
+use super::{Observer, Spd};
+
+///The result of a metamerism comparison.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Metamerism<T> {
+    ///The CIELAB color difference, ΔE\*ab, between the samples under the
+    ///reference conditions, before correction. It shows how good the match
+    ///is to begin with.
+    pub reference_difference: T,
+    ///The special metamerism index, which is the CIELAB color difference,
+    ///ΔE\*ab, between the samples under the test conditions, after the
+    ///correction.
+    pub index: T,
+}
+
+///The special metamerism index for a change in illuminant.
+///
+///The reflectances `first` and `second` are compared under the `reference`
+///illuminant, where they are expected to match, and then under the `test`
+///illuminant, both as seen by `observer`.
+pub fn illuminant_metamerism<T>(
+    first: &Spd<T>,
+    second: &Spd<T>,
+    reference: &Spd<T>,
+    test: &Spd<T>,
+    observer: Observer,
+) -> Metamerism<T>
+where
+    T: Component + Float,
+{
+    metamerism(first, second, (reference, observer), (test, observer))
+}
+
+///The special metamerism index for a change in observer.
+///
+///The reflectances `first` and `second` are compared under `illuminant` as
+///seen by the `reference` observer, where they are expected to match, and then
+///as seen by the `test` observer.
+pub fn observer_metamerism<T>(
+    first: &Spd<T>,
+    second: &Spd<T>,
+    illuminant: &Spd<T>,
+    reference: Observer,
+    test: Observer,
+) -> Metamerism<T>
+where
+    T: Component + Float,
+{
+    metamerism(first, second, (illuminant, reference), (illuminant, test))
+}
+
+fn metamerism<T>(
+    first: &Spd<T>,
+    second: &Spd<T>,
+    reference: (&Spd<T>, Observer),
+    test: (&Spd<T>, Observer),
+) -> Metamerism<T>
+where
+    T: Component + Float,
+{
+    let first_reference: Xyz<E, T> = first.reflective_xyz(reference.0, reference.1);
+    let second_reference: Xyz<E, T> = second.reflective_xyz(reference.0, reference.1);
+    let first_test: Xyz<E, T> = first.reflective_xyz(test.0, test.1);
+    let second_test: Xyz<E, T> = second.reflective_xyz(test.0, test.1);
+
+    let corrected: Xyz<E, T> = Xyz::with_wp(
+        second_test.x * first_reference.x / second_reference.x,
+        second_test.y * first_reference.y / second_reference.y,
+        second_test.z * first_reference.z / second_reference.z,
+    );
+
+    let reference_white = reference.0.white_point(reference.1);
+    let test_white = test.0.white_point(test.1);
+
+    Metamerism {
+        reference_difference: cie76(
+            &Lab::from_xyz_with_white(first_reference, &reference_white),
+            &Lab::from_xyz_with_white(second_reference, &reference_white),
+        ),
+        index: cie76(
+            &Lab::from_xyz_with_white(first_test, &test_white),
+            &Lab::from_xyz_with_white(corrected, &test_white),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use spectral::{illuminant, Observer, Spd};
+    use super::{illuminant_metamerism, observer_metamerism};
+
+    fn wavelengths() -> Vec<f64> {
+        (0..81).map(|i| 380.0 + 5.0 * i as f64).collect()
+    }
+
+    ///A reflectance that matches `base` under `illuminant`, for `observer`,
+    ///made by adding a metameric black to it.
+    fn metamer(base: &[f64], illuminant: &Spd<f64>, observer: Observer) -> Vec<f64> {
+        //Orthonormal basis for the weighted color matching functions
+        let mut basis: Vec<Vec<f64>> = Vec::new();
+        for channel in 0..3 {
+            let mut vector: Vec<f64> = wavelengths()
+                .iter()
+                .map(|&wavelength| observer.cmf(wavelength)[channel] * illuminant.at(wavelength))
+                .collect();
+
+            for other in &basis {
+                let dot: f64 = vector.iter().zip(other).map(|(a, b)| a * b).sum();
+                for (value, &o) in vector.iter_mut().zip(other) {
+                    *value -= dot * o;
+                }
+            }
+
+            let length = vector.iter().map(|v| v * v).sum::<f64>().sqrt();
+            basis.push(vector.iter().map(|v| v / length).collect());
+        }
+
+        //A wavy perturbation, with the visible part removed
+        let mut black: Vec<f64> = wavelengths()
+            .iter()
+            .map(|&wavelength| 0.15 * (wavelength / 25.0).sin())
+            .collect();
+        for vector in &basis {
+            let dot: f64 = black.iter().zip(vector).map(|(a, b)| a * b).sum();
+            for (value, &v) in black.iter_mut().zip(vector) {
+                *value -= dot * v;
+            }
+        }
+
+        base.iter().zip(black).map(|(b, m)| b + m).collect()
+    }
+
+    #[test]
+    fn identical_samples() {
+        let sample = Spd::new(380.0, 5.0, wavelengths().iter().map(|w| w / 1000.0).collect());
+        let metamerism = illuminant_metamerism(
+            &sample,
+            &sample,
+            &illuminant::d65(),
+            &illuminant::a(),
+            Observer::Cie1931,
+        );
+
+        assert_relative_eq!(metamerism.reference_difference, 0.0);
+        assert_relative_eq!(metamerism.index, 0.0);
+    }
+
+    #[test]
+    fn corrected_mismatch() {
+        //Flat reflectances only differ in lightness under every illuminant
+        let first = Spd::new(380.0, 5.0, vec![0.5; 81]);
+        let second = Spd::new(380.0, 5.0, vec![0.6; 81]);
+        let metamerism = illuminant_metamerism(
+            &first,
+            &second,
+            &illuminant::d65(),
+            &illuminant::fluorescent(illuminant::Fluorescent::F11),
+            Observer::Cie1931,
+        );
+
+        assert!(metamerism.reference_difference > 5.0);
+        assert_relative_eq!(metamerism.index, 0.0, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn illuminant_metamers() {
+        let d65 = illuminant::d65();
+        let base = vec![0.5; 81];
+        let first = Spd::new(380.0, 5.0, base.clone());
+        let second = Spd::new(380.0, 5.0, metamer(&base, &d65, Observer::Cie1931));
+
+        let under_a =
+            illuminant_metamerism(&first, &second, &d65, &illuminant::a(), Observer::Cie1931);
+        assert_relative_eq!(under_a.reference_difference, 0.0, epsilon = 0.0001);
+        assert!(under_a.index > 0.5);
+
+        let under_d65 = illuminant_metamerism(&first, &second, &d65, &d65, Observer::Cie1931);
+        assert_relative_eq!(under_d65.index, 0.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn observer_metamers() {
+        let d65 = illuminant::d65();
+        let base = vec![0.5; 81];
+        let first = Spd::new(380.0, 5.0, base.clone());
+        let second = Spd::new(380.0, 5.0, metamer(&base, &d65, Observer::Cie1931));
+
+        let metamerism =
+            observer_metamerism(&first, &second, &d65, Observer::Cie1931, Observer::Cie1964);
+        assert_relative_eq!(metamerism.reference_difference, 0.0, epsilon = 0.0001);
+        assert!(metamerism.index > 0.1);
+
+        let same = observer_metamerism(&first, &second, &d65, Observer::Cie1931, Observer::Cie1931);
+        assert_relative_eq!(same.index, 0.0, epsilon = 0.0001);
+    }
+}
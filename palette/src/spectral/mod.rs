@@ -0,0 +1,352 @@
+//!Spectral power distributions and colorimetric integration.
+//!
+//!A spectrum, such as the output of a spectroradiometer or the reflectance of
+//!a surface, is represented by an [`Spd`](struct.Spd.html). It can be
+//!integrated with the color matching functions of an
+//![`Observer`](enum.Observer.html) to get its `Xyz` tristimulus values.
+//!
+//!```
+//!use palette::Xyz;
+//!use palette::white_point::D65;
+//!use palette::spectral::{illuminant, Observer, Spd};
+//!
+//!//A flat 50% gray reflectance, measured from 400 nm to 700 nm
+//!let gray = Spd::new(400.0, 10.0, vec![0.5; 31]);
+//!
+//!let color: Xyz<D65, f64> = gray.reflective_xyz(&illuminant::d65(), Observer::Cie1931);
+//!assert!((color.y - 0.5).abs() < 0.001);
+//!```
+
+use num_traits::Float;
+
+use {cast, Component, Xyz};
+use white_point::{DynamicWhitePoint, WhitePoint};
+use cie_data::{self, CIE_1931_2, CIE_1964_10, CMF_INTERVAL, CMF_START};
+
+pub mod color_rendering;
+pub mod illuminant;
+pub mod metamerism;
+pub mod pigment;
+pub mod upsampling;
+
+///The maximum luminous efficacy, in lm/W, for photopic vision.
+const MAX_LUMINOUS_EFFICACY: f64 = 683.0;
+
+///A standard colorimetric observer, defined by its color matching functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Observer {
+    ///The CIE 1931 2° standard observer.
+    Cie1931,
+    ///The CIE 1964 10° supplementary standard observer.
+    Cie1964,
+}
+
+impl Observer {
+    ///The color matching functions, x̄, ȳ and z̄, at `wavelength`, in nm.
+    ///
+    ///The functions are tabulated from 380 nm to 780 nm in steps of 5 nm,
+    ///linearly interpolated in between and `0.0` outside of that range.
+    pub fn cmf<T: Float>(&self, wavelength: T) -> [T; 3] {
+        let wavelength_f64 = wavelength.to_f64().unwrap();
+        let end = CMF_START + CMF_INTERVAL * (CIE_1931_2.len() - 1) as f64;
+
+        if wavelength_f64 < CMF_START || wavelength_f64 > end {
+            return [T::zero(); 3];
+        }
+
+        cie_data::interpolate(self.table(), CMF_START, CMF_INTERVAL, wavelength)
+    }
+
+    fn table(&self) -> &'static [[f64; 3]] {
+        match *self {
+            Observer::Cie1931 => &CIE_1931_2,
+            Observer::Cie1964 => &CIE_1964_10,
+        }
+    }
+
+    ///Integrate `power` with the color matching functions, over the tabulated
+    ///wavelengths.
+    fn integrate<T: Float, F: Fn(T) -> T>(&self, power: F) -> [T; 3] {
+        let interval: T = cast(CMF_INTERVAL);
+        let mut xyz = [T::zero(); 3];
+
+        for (i, cmf) in self.table().iter().enumerate() {
+            let power = power(cast(CMF_START + CMF_INTERVAL * i as f64));
+
+            for (sum, &value) in xyz.iter_mut().zip(cmf) {
+                *sum = *sum + power * cast(value) * interval;
+            }
+        }
+
+        xyz
+    }
+}
+
+///The wavelength that describes the hue of a color, relative to a white point.
+///
+///It's found where the line from the white point, through the color, meets the
+///spectral locus in the chromaticity diagram. Purple colors are closer to the
+///line between the ends of the spectral locus, so they are described by the
+///complementary wavelength, where the line meets the spectral locus on the
+///other side of the white point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Wavelength<T> {
+    ///The dominant wavelength, in nm.
+    Dominant(T),
+    ///The complementary wavelength, in nm, for purple colors.
+    Complementary(T),
+}
+
+///The dominant or complementary wavelength and the purity of a color, from
+///[`Yxy::dominant_wavelength`](../struct.Yxy.html#method.dominant_wavelength).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DominantWavelength<T> {
+    ///The dominant or complementary wavelength.
+    pub wavelength: Wavelength<T>,
+    ///The excitation purity, which is the distance from the white point to
+    ///the color, relative to the distance from the white point to the spectral
+    ///locus or the purple line. It's `0.0` for the white point and `1.0` on the
+    ///border.
+    pub excitation_purity: T,
+    ///The colorimetric purity, which is the fraction of the luminance that
+    ///comes from the monochromatic or purple stimulus, when the color is made
+    ///by mixing it with white.
+    pub colorimetric_purity: T,
+}
+
+///A spectral power distribution, sampled at evenly spaced wavelengths.
+///
+///The values may be absolute, such as spectral radiance, or relative, such as
+///the relative power of an illuminant or the reflectance of a surface.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spd<T> {
+    start: T,
+    interval: T,
+    values: Vec<T>,
+}
+
+impl<T: Component + Float> Spd<T> {
+    ///Create a spectral power distribution from `values`, sampled every
+    ///`interval` nm from `start` nm.
+    ///
+    ///## Panics
+    ///
+    ///Panics if `values` is empty or if `interval` is not positive.
+    pub fn new(start: T, interval: T, values: Vec<T>) -> Spd<T> {
+        assert!(!values.is_empty(), "a spectral power distribution needs at least one value");
+        assert!(interval > T::zero(), "the wavelength interval must be positive");
+
+        Spd {
+            start: start,
+            interval: interval,
+            values: values,
+        }
+    }
+
+    ///Create a spectral power distribution by sampling `power` every
+    ///`interval` nm from `start` nm to `end` nm.
+    ///
+    ///## Panics
+    ///
+    ///Panics if `end` is less than `start` or if `interval` is not positive.
+    pub fn from_fn<F: FnMut(T) -> T>(start: T, end: T, interval: T, mut power: F) -> Spd<T> {
+        assert!(end >= start, "the end wavelength must not be less than the start");
+        assert!(interval > T::zero(), "the wavelength interval must be positive");
+
+        let count = ((end - start) / interval + cast(1e-6)).floor().to_usize().unwrap() + 1;
+        let values = (0..count)
+            .map(|i| power(start + interval * cast(i as f64)))
+            .collect();
+
+        Spd::new(start, interval, values)
+    }
+
+    ///The first wavelength, in nm.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    ///The last wavelength, in nm.
+    pub fn end(&self) -> T {
+        self.start + self.interval * cast((self.values.len() - 1) as f64)
+    }
+
+    ///The distance between the wavelengths, in nm.
+    pub fn interval(&self) -> T {
+        self.interval
+    }
+
+    ///The sampled values, from the first to the last wavelength.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    ///The value at `wavelength`, in nm, linearly interpolated between the
+    ///samples. Wavelengths outside of the sampled range give `0.0`.
+    pub fn at(&self, wavelength: T) -> T {
+        if wavelength < self.start || wavelength > self.end() {
+            T::zero()
+        } else {
+            self.interpolate(wavelength)
+        }
+    }
+
+    ///The value at `wavelength`, in nm, linearly interpolated between the
+    ///samples. Wavelengths outside of the sampled range give the nearest
+    ///value, as recommended for reflectances in CIE 15.
+    pub fn at_clamped(&self, wavelength: T) -> T {
+        self.interpolate(wavelength)
+    }
+
+    fn interpolate(&self, wavelength: T) -> T {
+        let last = self.values.len() - 1;
+        let position = (wavelength - self.start) / self.interval;
+
+        if position <= T::zero() {
+            return self.values[0];
+        }
+        if position >= cast(last as f64) {
+            return self.values[last];
+        }
+
+        let index = position.floor().to_usize().unwrap();
+        let fraction = position - position.floor();
+        let low = self.values[index];
+        let high = self.values[index + 1];
+        low + (high - low) * fraction
+    }
+
+    ///Resample the distribution every `interval` nm from `start` nm to `end`
+    ///nm, using linear interpolation. New wavelengths outside of the original
+    ///range get the value `0.0`.
+    pub fn resample(&self, start: T, end: T, interval: T) -> Spd<T> {
+        Spd::from_fn(start, end, interval, |wavelength| self.at(wavelength))
+    }
+
+    ///Multiply every value with `factor`.
+    pub fn scale(&self, factor: T) -> Spd<T> {
+        Spd {
+            start: self.start,
+            interval: self.interval,
+            values: self.values.iter().map(|&value| value * factor).collect(),
+        }
+    }
+
+    ///The tristimulus values of an emissive source with this spectral power
+    ///distribution, as seen by `observer`.
+    ///
+    ///The values are multiplied by the maximum luminous efficacy, 683 lm/W,
+    ///so a spectral radiance in W/(sr·m²·nm) gives a luminance, `y`, in
+    ///cd/m².
+    pub fn emissive_xyz<Wp: WhitePoint>(&self, observer: Observer) -> Xyz<Wp, T> {
+        let xyz = observer.integrate(|wavelength| self.at(wavelength));
+        let efficacy: T = cast(MAX_LUMINOUS_EFFICACY);
+        Xyz::with_wp(xyz[0] * efficacy, xyz[1] * efficacy, xyz[2] * efficacy)
+    }
+
+    ///The white point of a light source with this spectral power
+    ///distribution, as seen by `observer`. It's normalized to make `y` equal
+    ///to `1.0`.
+    pub fn white_point(&self, observer: Observer) -> DynamicWhitePoint<T> {
+        let xyz = observer.integrate(|wavelength| self.at(wavelength));
+        DynamicWhitePoint::new(xyz[0] / xyz[1], T::one(), xyz[2] / xyz[1])
+    }
+
+    ///The tristimulus values of a surface with this spectral reflectance, from
+    ///`0.0` to `1.0`, under `illuminant`, as seen by `observer`.
+    ///
+    ///The values are normalized to make `y` equal to `1.0` for a perfect
+    ///reflecting diffuser, so a reflectance of `1.0` gives the white point of
+    ///the illuminant.
+    pub fn reflective_xyz<Wp: WhitePoint>(
+        &self,
+        illuminant: &Spd<T>,
+        observer: Observer,
+    ) -> Xyz<Wp, T> {
+        let xyz = observer
+            .integrate(|wavelength| self.at_clamped(wavelength) * illuminant.at(wavelength));
+        let white_y = observer.integrate(|wavelength| illuminant.at(wavelength))[1];
+        Xyz::with_wp(xyz[0] / white_y, xyz[1] / white_y, xyz[2] / white_y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use Xyz;
+    use white_point::{D50, D65, D65Degree10, E, WhitePoint, A};
+    use super::{illuminant, Observer, Spd};
+
+    #[test]
+    fn interpolation() {
+        let spd = Spd::new(400.0, 10.0, vec![1.0, 2.0, 4.0]);
+
+        assert_eq!(spd.end(), 420.0);
+        assert_eq!(spd.at(405.0), 1.5);
+        assert_eq!(spd.at(417.5), 3.5);
+        assert_eq!(spd.at(420.0), 4.0);
+        assert_eq!(spd.at(390.0), 0.0);
+        assert_eq!(spd.at(430.0), 0.0);
+        assert_eq!(spd.at_clamped(390.0), 1.0);
+        assert_eq!(spd.at_clamped(430.0), 4.0);
+    }
+
+    #[test]
+    fn resampling() {
+        let spd = Spd::new(400.0, 10.0, vec![1.0, 2.0, 4.0]);
+        let resampled = spd.resample(395.0, 420.0, 5.0);
+
+        assert_eq!(resampled.start(), 395.0);
+        assert_eq!(resampled.interval(), 5.0);
+        assert_eq!(resampled.values(), &[0.0, 1.0, 1.5, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn color_matching_functions() {
+        assert_eq!(Observer::Cie1931.cmf(555.0f64), [0.512050, 1.0, 0.005750]);
+        assert_eq!(Observer::Cie1964.cmf(380.0f64), [0.000160, 0.000017, 0.000705]);
+
+        let between = Observer::Cie1931.cmf(552.5f64);
+        assert_relative_eq!(between[0], (0.433450 + 0.512050) / 2.0);
+        assert_eq!(Observer::Cie1931.cmf(300.0f64), [0.0; 3]);
+        assert_eq!(Observer::Cie1964.cmf(800.0f64), [0.0; 3]);
+    }
+
+    #[test]
+    fn white_points() {
+        let equal_energy = Spd::new(380.0, 5.0, vec![1.0; 81]);
+        for &observer in &[Observer::Cie1931, Observer::Cie1964] {
+            let white: Xyz<E, f64> = equal_energy.white_point(observer).get_xyz();
+            assert_relative_eq!(white, E::get_xyz(), epsilon = 0.0002);
+        }
+
+        let d65 = illuminant::d65::<f64>();
+        assert!(d65.white_point(Observer::Cie1931).is_close_to::<D65>(0.0005));
+        assert!(d65.white_point(Observer::Cie1964).is_close_to::<D65Degree10>(0.0005));
+        assert!(illuminant::d50::<f64>().white_point(Observer::Cie1931).is_close_to::<D50>(0.0005));
+        assert!(illuminant::a::<f64>().white_point(Observer::Cie1931).is_close_to::<A>(0.001));
+    }
+
+    #[test]
+    fn emissive() {
+        //1 W/(sr·m²·nm) over the visible range
+        let spd = Spd::new(380.0, 5.0, vec![1.0; 81]);
+        let color: Xyz<E, f64> = spd.emissive_xyz(Observer::Cie1931);
+        assert_relative_eq!(color.y, 683.0 * 106.857, max_relative = 0.0001);
+
+        let double: Xyz<E, f64> = spd.scale(2.0).emissive_xyz(Observer::Cie1931);
+        assert_relative_eq!(double.y, color.y * 2.0);
+    }
+
+    #[test]
+    fn reflective() {
+        let d65 = illuminant::d65();
+        let white: Xyz<D65, f64> =
+            Spd::new(380.0, 10.0, vec![1.0; 41]).reflective_xyz(&d65, Observer::Cie1931);
+        assert_relative_eq!(white, D65::get_xyz(), epsilon = 0.0005);
+
+        //A partial range is extended with the nearest values
+        let gray: Xyz<D65, f64> =
+            Spd::new(400.0, 20.0, vec![0.2; 16]).reflective_xyz(&d65, Observer::Cie1931);
+        assert_relative_eq!(gray, white * 0.2, epsilon = 0.000001);
+    }
+}
@@ -0,0 +1,218 @@
+//!Pigment mixing with the Kubelka–Munk theory.
+//!
+//!Mixing colors with [`Mix`](../../trait.Mix.html) interpolates them in their
+//!color space, which works like mixing light. Paint behaves differently,
+//!since each pigment absorbs and scatters light. Mixing a blue and a yellow
+//!paint gives a green paint, where mixing blue and yellow light gives gray.
+//!
+//!The Kubelka–Munk theory describes a layer of paint, thick enough to hide
+//!what's below it, with an absorption coefficient `K` and a scattering
+//!coefficient `S` for each wavelength. The coefficients of a mix are the
+//!weighted sums of the coefficients of its pigments, and its reflectance is
+//!`1 + K/S - sqrt((K/S)² + 2 K/S)`.
+//!
+//!Pigments that are made from RGB colors get their reflectance from the
+//![`jakob_hanika`](../upsampling/fn.jakob_hanika.html) spectral upsampling and
+//!a scattering coefficient of `1.0` at every wavelength. The colors are seen
+//!under CIE illuminant D65, by the CIE 1931 observer.
+//!
+//!```
+//!use palette::LinSrgb;
+//!use palette::spectral::pigment::mix_pigments;
+//!
+//!let blue = LinSrgb::new(0.05f64, 0.1, 0.6);
+//!let yellow = LinSrgb::new(0.8, 0.7, 0.05);
+//!
+//!let mix = mix_pigments(&[(blue, 1.0), (yellow, 1.0)]).unwrap();
+//!assert!(mix.green > mix.red && mix.green > mix.blue);
+//!```
+
+use num_traits::Float;
+
+use {cast, Component, Xyz};
+use rgb::{Rgb, RgbSpace};
+use encoding::Linear;
+use white_point::{WhitePoint, D65};
+use chromatic_adaptation::Adapter;
+use super::{illuminant, Observer, Spd};
+use super::upsampling::jakob_hanika;
+
+///A pigment, described by its absorption and scattering coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pigment<T> {
+    ///The absorption coefficient, `K`, for each wavelength.
+    pub absorption: Spd<T>,
+    ///The scattering coefficient, `S`, for each wavelength.
+    pub scattering: Spd<T>,
+}
+
+impl<T: Component + Float> Pigment<T> {
+    ///Make a pigment with the same scattering coefficient, `1.0`, at every
+    ///wavelength, and an absorption coefficient that gives `reflectance`.
+    ///
+    ///The reflectance is clamped to be larger than `0.0001` and at most `1.0`.
+    pub fn from_reflectance(reflectance: &Spd<T>) -> Pigment<T> {
+        let minimum: T = cast(0.0001);
+        let absorption = reflectance
+            .values()
+            .iter()
+            .map(|&value| {
+                let value = value.max(minimum).min(T::one());
+                (T::one() - value).powi(2) / (cast::<T, _>(2.0) * value)
+            })
+            .collect();
+
+        Pigment {
+            absorption: Spd::new(reflectance.start(), reflectance.interval(), absorption),
+            scattering: Spd::new(
+                reflectance.start(),
+                reflectance.interval(),
+                vec![T::one(); reflectance.values().len()],
+            ),
+        }
+    }
+
+    ///Make a pigment with the color `color`. Returns `None` if the color can't
+    ///be upsampled to a reflectance.
+    pub fn from_rgb<S: RgbSpace>(color: Rgb<Linear<S>, T>) -> Option<Pigment<T>> {
+        jakob_hanika(color).map(|polynomial| Pigment::from_reflectance(&polynomial.spd()))
+    }
+
+    ///Mix pigments, where each pigment has a weight for how much of it there
+    ///is in the mix. The weights don't have to add up to `1.0`. Returns `None`
+    ///if there are no pigments, or if a weight is negative or they are all
+    ///zero.
+    ///
+    ///The mix is sampled every 5 nm from 380 nm to 780 nm.
+    pub fn mix(pigments: &[(&Pigment<T>, T)]) -> Option<Pigment<T>> {
+        let total = pigments
+            .iter()
+            .fold(T::zero(), |sum, &(_, weight)| sum + weight);
+        let negative = pigments.iter().any(|&(_, weight)| weight < T::zero());
+        if negative || total <= T::zero() || total.is_nan() {
+            return None;
+        }
+
+        let weighted_sum = |coefficient: &dyn Fn(&Pigment<T>) -> &Spd<T>| {
+            Spd::from_fn(cast(380.0), cast(780.0), cast(5.0), |wavelength| {
+                pigments.iter().fold(T::zero(), |sum, &(pigment, weight)| {
+                    sum + coefficient(pigment).at_clamped(wavelength) * weight / total
+                })
+            })
+        };
+
+        Some(Pigment {
+            absorption: weighted_sum(&|pigment| &pigment.absorption),
+            scattering: weighted_sum(&|pigment| &pigment.scattering),
+        })
+    }
+
+    ///The reflectance of a layer of the pigment that hides what's below it.
+    pub fn reflectance(&self) -> Spd<T> {
+        let start = self.absorption.start().max(self.scattering.start());
+        let end = self.absorption.end().min(self.scattering.end());
+        let interval = self.absorption.interval().min(self.scattering.interval());
+
+        Spd::from_fn(start, end, interval, |wavelength| {
+            let ratio = self.absorption.at_clamped(wavelength)
+                / self.scattering.at_clamped(wavelength);
+            T::one() + ratio - (ratio * ratio + cast::<T, _>(2.0) * ratio).sqrt()
+        })
+    }
+
+    ///The color of the pigment, under CIE illuminant D65, in the RGB space
+    ///`S`.
+    pub fn to_rgb<S: RgbSpace>(&self) -> Rgb<Linear<S>, T> {
+        let illuminant = illuminant::d65();
+        let white: Xyz<D65, T> = illuminant.white_point(Observer::Cie1931).get_xyz();
+        let xyz: Xyz<D65, T> = self.reflectance()
+            .reflective_xyz(&illuminant, Observer::Cie1931);
+
+        //Make the white of the integration match the white of the RGB space
+        Adapter::<D65, S::WhitePoint, T>::new().adapt(xyz / white * D65::get_xyz())
+    }
+}
+
+///Mix colors as if they were pigments, where each color has a weight for how
+///much of it there is in the mix. The weights don't have to add up to `1.0`.
+///
+///Returns `None` if a color can't be upsampled to a reflectance, if there are
+///no colors, or if a weight is negative or they are all zero.
+pub fn mix_pigments<S, T>(colors: &[(Rgb<Linear<S>, T>, T)]) -> Option<Rgb<Linear<S>, T>>
+where
+    S: RgbSpace,
+    T: Component + Float,
+{
+    let pigments = colors
+        .iter()
+        .map(|&(color, weight)| Pigment::from_rgb(color).map(|pigment| (pigment, weight)))
+        .collect::<Option<Vec<_>>>()?;
+    let pigments: Vec<_> = pigments
+        .iter()
+        .map(|&(ref pigment, weight)| (pigment, weight))
+        .collect();
+
+    Pigment::mix(&pigments).map(|mix| mix.to_rgb())
+}
+
+#[cfg(test)]
+mod test {
+    use LinSrgb;
+    use encoding::Srgb;
+    use spectral::Spd;
+    use super::{mix_pigments, Pigment};
+
+    #[test]
+    fn blue_and_yellow() {
+        let blue = LinSrgb::new(0.05f64, 0.1, 0.6);
+        let yellow = LinSrgb::new(0.8, 0.7, 0.05);
+
+        let mix = mix_pigments(&[(blue, 1.0), (yellow, 1.0)]).unwrap();
+        assert!(mix.green > mix.red);
+        assert!(mix.green > mix.blue);
+    }
+
+    #[test]
+    fn single_color() {
+        let color = LinSrgb::new(0.3f64, 0.5, 0.2);
+        assert_relative_eq!(mix_pigments(&[(color, 1.0)]).unwrap(), color, epsilon = 0.001);
+        assert_relative_eq!(
+            mix_pigments(&[(color, 0.5), (color, 2.0)]).unwrap(),
+            color,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            mix_pigments(&[(color, 1.0), (LinSrgb::new(0.9, 0.1, 0.1), 0.0)]).unwrap(),
+            color,
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn invalid_weights() {
+        let color = LinSrgb::new(0.3f64, 0.5, 0.2);
+        assert_eq!(mix_pigments::<Srgb, f64>(&[]), None);
+        assert_eq!(mix_pigments(&[(color, 0.0)]), None);
+        assert_eq!(mix_pigments(&[(color, 1.0), (color, -0.5)]), None);
+    }
+
+    #[test]
+    fn reflectance_round_trip() {
+        let reflectance = Spd::new(380.0, 5.0, (0..81).map(|i| 0.1 + i as f64 / 100.0).collect());
+        let pigment = Pigment::from_reflectance(&reflectance);
+
+        for (&expected, &value) in reflectance.values().iter().zip(pigment.reflectance().values()) {
+            assert_relative_eq!(expected, value, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn white_lightens() {
+        let red = LinSrgb::new(0.6f64, 0.05, 0.05);
+        let white = LinSrgb::new(0.9, 0.9, 0.9);
+
+        let mix = mix_pigments(&[(red, 1.0), (white, 1.0)]).unwrap();
+        assert!(mix.green > red.green);
+        assert!(mix.red > mix.green);
+    }
+}
@@ -0,0 +1,430 @@
+//!Spectral upsampling, from RGB colors to smooth reflectance spectra.
+//!
+//!Many spectra have the same RGB color, so these methods pick a smooth one
+//!that is suitable for spectral rendering. Both methods make spectra for a
+//!surface under CIE illuminant D65, as seen by the CIE 1931 observer. Colors
+//!in RGB spaces with other white points are chromatically adapted to D65 with
+//!the Bradford method first.
+//!
+//! * [`smits`](fn.smits.html) is the method from Smits (1999), which mixes
+//!   seven fixed spectra. It's fast, but only supports colors within the sRGB
+//!   gamut, and it's not exact. The color difference after a round trip is at
+//!   most `SMITS_MAX_DELTA_E`, ΔE\*ab, where the largest differences are for
+//!   saturated blue colors.
+//! * [`jakob_hanika`](fn.jakob_hanika.html) fits a sigmoid of a quadratic
+//!   polynomial to each color, as in Jakob and Hanika (2019). It supports any
+//!   color that a smooth reflectance can have and the color difference after
+//!   a round trip is at most `JAKOB_HANIKA_MAX_DELTA_E`, ΔE\*ab. The fit is
+//!   done per color, so it's much slower than looking the coefficients up in a
+//!   precomputed table.
+//!
+//!```
+//!use palette::{LinSrgb, Xyz};
+//!use palette::white_point::D65;
+//!use palette::spectral::{illuminant, Observer};
+//!use palette::spectral::upsampling::jakob_hanika;
+//!
+//!let color = LinSrgb::new(0.2f64, 0.6, 0.3);
+//!let reflectance = jakob_hanika(color).unwrap().spd();
+//!
+//!//Back through spectral integration
+//!let xyz: Xyz<D65, f64> = reflectance.reflective_xyz(&illuminant::d65(), Observer::Cie1931);
+//!let round_trip = LinSrgb::from(xyz);
+//!assert!((round_trip.green - 0.6).abs() < 0.001);
+//!```
+
+use num_traits::Float;
+
+use {cast, Component, Lab, Xyz};
+use rgb::{Rgb, RgbSpace};
+use encoding::{Linear, Srgb};
+use white_point::{WhitePoint, D65};
+use chromatic_adaptation::Adapter;
+use super::{illuminant, Observer, Spd};
+
+///The largest CIELAB color difference, ΔE\*ab, between a color and the
+///spectrum from [`smits`](fn.smits.html) under D65.
+pub const SMITS_MAX_DELTA_E: f64 = 9.0;
+
+///The largest CIELAB color difference, ΔE\*ab, between a color and the
+///spectrum from [`jakob_hanika`](fn.jakob_hanika.html) under D65.
+pub const JAKOB_HANIKA_MAX_DELTA_E: f64 = 0.01;
+
+///The wavelength, in nm, where the spectra from `smits` start.
+const SMITS_START: f64 = 380.0;
+
+///The width, in nm, of each of the 10 bins in the spectra from `smits`.
+const SMITS_BIN_WIDTH: f64 = 34.0;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SMITS_WHITE: [f64; 10] = [1.0000, 1.0000, 0.9999, 0.9993, 0.9992, 0.9998, 1.0000, 1.0000, 1.0000, 1.0000];
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SMITS_CYAN: [f64; 10] = [0.9710, 0.9426, 1.0007, 1.0007, 1.0007, 1.0007, 0.1564, 0.0000, 0.0000, 0.0000];
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SMITS_MAGENTA: [f64; 10] = [1.0000, 1.0000, 0.9685, 0.2229, 0.0000, 0.0458, 0.8369, 1.0000, 1.0000, 0.9959];
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SMITS_YELLOW: [f64; 10] = [0.0001, 0.0000, 0.1088, 0.6651, 1.0000, 1.0000, 0.9996, 0.9586, 0.9685, 0.9840];
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SMITS_RED: [f64; 10] = [0.1012, 0.0515, 0.0000, 0.0000, 0.0000, 0.0000, 0.8325, 1.0149, 1.0149, 1.0149];
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SMITS_GREEN: [f64; 10] = [0.0000, 0.0000, 0.0273, 0.7937, 1.0000, 0.9418, 0.1719, 0.0000, 0.0000, 0.0025];
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const SMITS_BLUE: [f64; 10] = [1.0000, 1.0000, 0.8916, 0.3323, 0.0000, 0.0000, 0.0003, 0.0369, 0.0483, 0.0496];
+
+///Make a reflectance spectrum for `color` with the method from Smits (1999).
+///
+///The spectrum is piecewise constant, with 10 bins from 380 nm to 720 nm,
+///where the last bin continues to 780 nm. It's sampled every 5 nm from 380
+///nm to 780 nm. Returns `None` if `color` is outside of the sRGB gamut.
+pub fn smits<S, T>(color: Rgb<Linear<S>, T>) -> Option<Spd<T>>
+where
+    S: RgbSpace,
+    T: Component + Float,
+{
+    let color = Adapter::<S::WhitePoint, D65, T>::new()
+        .rgb::<S, Srgb>()
+        .adapt(color);
+
+    let epsilon: T = cast(1.0e-6);
+    let in_gamut = |value: T| value >= -epsilon && value <= T::one() + epsilon;
+    if !in_gamut(color.red) || !in_gamut(color.green) || !in_gamut(color.blue) {
+        return None;
+    }
+
+    let (red, green, blue): (f64, f64, f64) =
+        (cast(color.red), cast(color.green), cast(color.blue));
+
+    //Start with the white part, then add the secondary and the primary color
+    let parts = if red <= green && red <= blue {
+        if green <= blue {
+            [(red, SMITS_WHITE), (green - red, SMITS_CYAN), (blue - green, SMITS_BLUE)]
+        } else {
+            [(red, SMITS_WHITE), (blue - red, SMITS_CYAN), (green - blue, SMITS_GREEN)]
+        }
+    } else if green <= red && green <= blue {
+        if red <= blue {
+            [(green, SMITS_WHITE), (red - green, SMITS_MAGENTA), (blue - red, SMITS_BLUE)]
+        } else {
+            [(green, SMITS_WHITE), (blue - green, SMITS_MAGENTA), (red - blue, SMITS_RED)]
+        }
+    } else if red <= green {
+        [(blue, SMITS_WHITE), (red - blue, SMITS_YELLOW), (green - red, SMITS_GREEN)]
+    } else {
+        [(blue, SMITS_WHITE), (green - blue, SMITS_YELLOW), (red - green, SMITS_RED)]
+    };
+
+    Some(Spd::from_fn(cast(380.0), cast(780.0), cast(5.0), |wavelength| {
+        let bin = ((cast::<f64, _>(wavelength) - SMITS_START) / SMITS_BIN_WIDTH) as usize;
+        let bin = bin.min(9);
+        cast(
+            parts
+                .iter()
+                .fold(0.0, |sum, &(weight, spectrum)| sum + weight * spectrum[bin]),
+        )
+    }))
+}
+
+///A reflectance spectrum of the form `sigmoid(c0 λ² + c1 λ + c2)`, where λ is
+///the wavelength in nm, as in Jakob and Hanika (2019).
+///
+///The sigmoid is `0.5 + x / (2 sqrt(1 + x²))`, so the reflectance is always
+///between `0.0` and `1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SigmoidPolynomial<T> {
+    ///The polynomial coefficients, `[c0, c1, c2]`.
+    pub coefficients: [T; 3],
+}
+
+impl<T: Component + Float> SigmoidPolynomial<T> {
+    ///The reflectance at `wavelength`, in nm.
+    pub fn at(&self, wavelength: T) -> T {
+        let [c0, c1, c2] = self.coefficients;
+        sigmoid((c0 * wavelength + c1) * wavelength + c2)
+    }
+
+    ///Sample the reflectance every 5 nm from 380 nm to 780 nm.
+    pub fn spd(&self) -> Spd<T> {
+        Spd::from_fn(cast(380.0), cast(780.0), cast(5.0), |wavelength| {
+            self.at(wavelength)
+        })
+    }
+}
+
+///Fit a smooth reflectance spectrum to `color`, with the method from Jakob and
+///Hanika (2019).
+///
+///Returns `None` if the color can't be matched by a reflectance of this form
+///within `JAKOB_HANIKA_MAX_DELTA_E`, which happens for colors that are too
+///saturated, too bright or too dark.
+pub fn jakob_hanika<S, T>(color: Rgb<Linear<S>, T>) -> Option<SigmoidPolynomial<T>>
+where
+    S: RgbSpace,
+    T: Component + Float,
+{
+    let target: Xyz<D65, T> = Adapter::<S::WhitePoint, D65, T>::new().adapt(color);
+    let target: Lab<D65, f64> = Xyz::with_wp(cast(target.x), cast(target.y), cast(target.z)).into();
+
+    //The fit is done with wavelengths mapped to [0, 1], for better conditioning
+    let fit = SigmoidFit::new();
+    let mut coefficients = [0.0; 3];
+    let mut error = fit.residual(coefficients, &target);
+    let mut damping = 0.001;
+
+    for _ in 0..MAX_ITERATIONS {
+        let distance = length(error);
+        if distance < JAKOB_HANIKA_MAX_DELTA_E * 0.1 {
+            break;
+        }
+
+        let jacobian = fit.jacobian(coefficients, &target);
+        let step = match levenberg_marquardt_step(&jacobian, error, damping) {
+            Some(step) => step,
+            None => break,
+        };
+
+        let candidate = [
+            coefficients[0] - step[0],
+            coefficients[1] - step[1],
+            coefficients[2] - step[2],
+        ];
+        let candidate_error = fit.residual(candidate, &target);
+
+        if length(candidate_error) < distance {
+            coefficients = candidate;
+            error = candidate_error;
+            damping = (damping * 0.1).max(1.0e-9);
+        } else {
+            damping *= 10.0;
+            if damping > 1.0e9 {
+                break;
+            }
+        }
+    }
+
+    if length(error) > JAKOB_HANIKA_MAX_DELTA_E {
+        return None;
+    }
+
+    //Change from the normalized wavelengths to nm
+    let [a, b, c] = coefficients;
+    let start = FIT_START;
+    let width = FIT_END - FIT_START;
+    Some(SigmoidPolynomial {
+        coefficients: [
+            cast(a / (width * width)),
+            cast(b / width - 2.0 * a * start / (width * width)),
+            cast(c - b * start / width + a * start * start / (width * width)),
+        ],
+    })
+}
+
+const FIT_START: f64 = 380.0;
+const FIT_END: f64 = 780.0;
+const MAX_ITERATIONS: usize = 100;
+
+///The fixed parts of the fit, with the reference illuminant and its white
+///point.
+struct SigmoidFit {
+    illuminant: Spd<f64>,
+    white: Xyz<D65, f64>,
+}
+
+impl SigmoidFit {
+    fn new() -> SigmoidFit {
+        let illuminant = illuminant::d65();
+        let white = illuminant.white_point(Observer::Cie1931).get_xyz();
+        SigmoidFit {
+            illuminant: illuminant,
+            white: white,
+        }
+    }
+
+    fn lab(&self, coefficients: [f64; 3]) -> Lab<D65, f64> {
+        let [a, b, c] = coefficients;
+        let reflectance = Spd::from_fn(FIT_START, FIT_END, 5.0, |wavelength| {
+            let x = (wavelength - FIT_START) / (FIT_END - FIT_START);
+            sigmoid((a * x + b) * x + c)
+        });
+        let xyz: Xyz<D65, f64> = reflectance.reflective_xyz(&self.illuminant, Observer::Cie1931);
+
+        //Make the white of the integration match the white of the target
+        let d65: Xyz<D65, f64> = D65::get_xyz();
+        (xyz / self.white * d65).into()
+    }
+
+    fn residual(&self, coefficients: [f64; 3], target: &Lab<D65, f64>) -> [f64; 3] {
+        let lab = self.lab(coefficients);
+        [lab.l - target.l, lab.a - target.a, lab.b - target.b]
+    }
+
+    ///The partial derivatives of the residual, by central differences. Each
+    ///row is the derivative for one coefficient.
+    fn jacobian(&self, coefficients: [f64; 3], target: &Lab<D65, f64>) -> [[f64; 3]; 3] {
+        let step = 1.0e-6;
+        let mut jacobian = [[0.0; 3]; 3];
+
+        for (coefficient, row) in jacobian.iter_mut().enumerate() {
+            let mut high = coefficients;
+            let mut low = coefficients;
+            high[coefficient] += step;
+            low[coefficient] -= step;
+            let high = self.residual(high, target);
+            let low = self.residual(low, target);
+            for (value, (high, low)) in row.iter_mut().zip(high.iter().zip(&low)) {
+                *value = (high - low) / (2.0 * step);
+            }
+        }
+
+        jacobian
+    }
+}
+
+///Solve `(JᵀJ + damping diag(JᵀJ)) step = Jᵀ error`, where the rows of
+///`jacobian` are the columns of J.
+fn levenberg_marquardt_step(
+    jacobian: &[[f64; 3]; 3],
+    error: [f64; 3],
+    damping: f64,
+) -> Option<[f64; 3]> {
+    let dot = |a: &[f64; 3], b: &[f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+
+    let mut normal = [[0.0; 3]; 3];
+    for i in 0..3 {
+        for j in 0..3 {
+            normal[i][j] = dot(&jacobian[i], &jacobian[j]);
+        }
+        normal[i][i] *= 1.0 + damping;
+    }
+    let gradient = [
+        dot(&jacobian[0], &error),
+        dot(&jacobian[1], &error),
+        dot(&jacobian[2], &error),
+    ];
+
+    //Cramer's rule
+    let determinant = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let denominator = determinant(&normal);
+    if !denominator.is_normal() {
+        return None;
+    }
+
+    let mut step = [0.0; 3];
+    for (column, value) in step.iter_mut().enumerate() {
+        let mut replaced = normal;
+        for (row, &g) in replaced.iter_mut().zip(&gradient) {
+            row[column] = g;
+        }
+        *value = determinant(&replaced) / denominator;
+    }
+
+    Some(step)
+}
+
+fn sigmoid<T: Float>(x: T) -> T {
+    if x.is_infinite() {
+        return if x > T::zero() { T::one() } else { T::zero() };
+    }
+
+    cast::<T, _>(0.5) + x / (cast::<T, _>(2.0) * (T::one() + x * x).sqrt())
+}
+
+fn length(vector: [f64; 3]) -> f64 {
+    (vector[0] * vector[0] + vector[1] * vector[1] + vector[2] * vector[2]).sqrt()
+}
+
+#[cfg(test)]
+mod test {
+    use {Lab, LinSrgb, Xyz};
+    use rgb::{Rgb, RgbSpace};
+    use encoding::{Linear, Rec2020};
+    use white_point::D65;
+    use color_difference::cie76;
+    use spectral::{illuminant, Observer, Spd};
+    use super::{jakob_hanika, smits, JAKOB_HANIKA_MAX_DELTA_E,
+                SMITS_MAX_DELTA_E};
+
+    ///The CIELAB color difference between `color` and `reflectance` under D65, for
+    ///testing the round trip.
+    fn round_trip_delta_e<S: RgbSpace<WhitePoint = D65>>(
+        color: Rgb<Linear<S>, f64>,
+        reflectance: &Spd<f64>,
+    ) -> f64 {
+        let illuminant = illuminant::d65();
+        let white = illuminant.white_point(Observer::Cie1931);
+        let xyz: Xyz<D65, f64> = reflectance.reflective_xyz(&illuminant, Observer::Cie1931);
+        let expected = Lab::from_xyz_with_white(Xyz::from(color), &D65.into());
+        cie76(&expected, &Lab::from_xyz_with_white(xyz, &white))
+    }
+
+    fn grid() -> Vec<LinSrgb<f64>> {
+        let steps = [0.0, 0.05, 0.2, 0.5, 0.8, 1.0];
+        let mut colors = Vec::new();
+        for &red in &steps {
+            for &green in &steps {
+                for &blue in &steps {
+                    colors.push(LinSrgb::new(red, green, blue));
+                }
+            }
+        }
+        colors
+    }
+
+    #[test]
+    fn smits_round_trip() {
+        for color in grid() {
+            let reflectance = smits(color).unwrap();
+            let delta_e = round_trip_delta_e(color, &reflectance);
+            assert!(delta_e <= SMITS_MAX_DELTA_E, "{:?}: {}", color, delta_e);
+        }
+    }
+
+    #[test]
+    fn smits_gray() {
+        let reflectance = smits(LinSrgb::new(0.5, 0.5, 0.5)).unwrap();
+        for &value in reflectance.values() {
+            assert_relative_eq!(value, 0.5, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn smits_out_of_gamut() {
+        assert_eq!(smits(LinSrgb::new(1.2, 0.5, 0.5)), None);
+        assert_eq!(smits(Rgb::<Linear<Rec2020>, f64>::new(0.0, 1.0, 0.0)), None);
+        assert!(smits(Rgb::<Linear<Rec2020>, f64>::new(0.5, 0.5, 0.5)).is_some());
+    }
+
+    #[test]
+    fn jakob_hanika_round_trip() {
+        for color in grid() {
+            let polynomial = jakob_hanika(color).unwrap();
+            let delta_e = round_trip_delta_e(color, &polynomial.spd());
+            assert!(delta_e <= JAKOB_HANIKA_MAX_DELTA_E, "{:?}: {}", color, delta_e);
+        }
+
+        let color = Rgb::<Linear<Rec2020>, f64>::new(0.3, 0.5, 0.2);
+        let polynomial = jakob_hanika(color).unwrap();
+        assert!(round_trip_delta_e(color, &polynomial.spd()) <= JAKOB_HANIKA_MAX_DELTA_E);
+    }
+
+    #[test]
+    fn jakob_hanika_support() {
+        assert!(jakob_hanika(LinSrgb::new(0.2, 0.6, 0.3)).is_some());
+        assert!(jakob_hanika(LinSrgb::new(0.8, 0.05, 0.05)).is_some());
+        assert!(jakob_hanika(LinSrgb::new(0.5, 0.5, 0.5)).is_some());
+        assert!(jakob_hanika(LinSrgb::new(2.0, 2.0, 2.0)).is_none());
+    }
+
+    #[test]
+    fn sigmoid_polynomial() {
+        let polynomial = jakob_hanika(LinSrgb::new(0.5f64, 0.5, 0.5)).unwrap();
+        let spd = polynomial.spd();
+        for &value in spd.values() {
+            assert!(value > 0.0 && value < 1.0);
+        }
+        assert_relative_eq!(polynomial.at(550.0), spd.at(550.0));
+    }
+}
@@ -0,0 +1,431 @@
+//!Limits of real surface colors.
+//!
+//!Not every color is possible as the color of a real, non-fluorescent
+//!surface. This module has two ways of checking it:
+//!
+//!* [`pointer_gamut`](fn.pointer_gamut.html) uses Pointer's gamut, which is
+//!  the range of measured real surface colors, as published by M. R. Pointer.
+//!* [`MacAdamLimits`](struct.MacAdamLimits.html) computes the theoretical
+//!  limits, which are the colors of the optimal reflectances that are either
+//!  `0` or `1` at each wavelength, under a given illuminant.
+//!
+//!Both give a [`SurfaceCheck`](struct.SurfaceCheck.html), with the distance to
+//!the boundary in `Lch` chroma.
+//!
+//!```
+//!use palette::Lch;
+//!use palette::surface::{pointer_gamut, MacAdamLimits};
+//!use palette::white_point::D65;
+//!
+//!let color = Lch::new(50.0f64, 40.0, 30.0);
+//!assert!(pointer_gamut(color).is_inside);
+//!
+//!let limits = MacAdamLimits::<D65>::daylight(6504.0);
+//!assert!(limits.check(color).is_inside);
+//!assert!(!limits.check(Lch::new(50.0f64, 200.0, 30.0)).is_inside);
+//!```
+
+use num_traits::Float;
+
+use std::marker::PhantomData;
+
+use {cast, Component, IntoColor, LabHue, Lch, Xyz};
+use chromatic_adaptation::AdaptFrom;
+use cie_data::{self, CIE_1931_2, CMF_INTERVAL, CMF_START};
+use white_point::{self, WhitePoint};
+
+///The result of checking if a color is a real surface color.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SurfaceCheck<T> {
+    ///`true` if the color is within the boundary.
+    pub is_inside: bool,
+
+    ///The difference in `Lch` chroma from the color to the boundary, at the
+    ///lightness and hue of the color. It's positive when the color is inside
+    ///and negative when it's outside.
+    pub distance: T,
+}
+
+impl<T: Float> SurfaceCheck<T> {
+    fn new(max_chroma: T, chroma: T) -> SurfaceCheck<T> {
+        SurfaceCheck {
+            is_inside: chroma <= max_chroma,
+            distance: max_chroma - chroma,
+        }
+    }
+}
+
+///Check if a color is within Pointer's gamut of real surface colors.
+///
+///Pointer's gamut is defined in `Lch` under illuminant C, so the color is
+///adapted to C with the Bradford method first. The data covers lightnesses
+///from `20` to `90`, and the boundary is linearly narrowed to a chroma of `0`
+///at the lightnesses `0` and `100` outside of that range.
+pub fn pointer_gamut<C, Wp, T>(color: C) -> SurfaceCheck<T>
+where
+    C: IntoColor<Wp, T>,
+    Wp: WhitePoint,
+    T: Component + Float,
+{
+    let lch: Lch<white_point::C, T> = Lch::adapt_from(color);
+    SurfaceCheck::new(pointer_max_chroma(lch.l, lch.hue), lch.chroma)
+}
+
+///The maximum chroma of Pointer's gamut for the lightness `l` and hue `hue`,
+///in `Lch` under illuminant C.
+pub fn pointer_max_chroma<T: Float>(l: T, hue: LabHue<T>) -> T {
+    let hue_steps = POINTER_GAMUT[0].len();
+    let h_position = hue.to_positive_degrees() / cast(10.0);
+    let h_index = h_position.floor().to_usize().unwrap_or(0) % hue_steps;
+    let h_next = (h_index + 1) % hue_steps;
+    let h_fraction = h_position - h_position.floor();
+
+    let at_row = |row: &[f64; 36]| {
+        let low: T = cast(row[h_index]);
+        let high: T = cast(row[h_next]);
+        low + (high - low) * h_fraction
+    };
+
+    let first = POINTER_LIGHTNESS_START;
+    let last = first + POINTER_LIGHTNESS_STEP * (POINTER_GAMUT.len() - 1) as f64;
+
+    if l <= T::zero() || l >= cast(100.0) {
+        T::zero()
+    } else if l < cast(first) {
+        at_row(&POINTER_GAMUT[0]) * l / cast(first)
+    } else if l > cast(last) {
+        at_row(&POINTER_GAMUT[POINTER_GAMUT.len() - 1]) * (cast::<T, _>(100.0) - l)
+            / cast(100.0 - last)
+    } else {
+        let position = (l - cast(first)) / cast(POINTER_LIGHTNESS_STEP);
+        let index = position
+            .floor()
+            .to_usize()
+            .unwrap_or(0)
+            .min(POINTER_GAMUT.len() - 2);
+        let fraction = position - T::from(index).unwrap();
+
+        let low = at_row(&POINTER_GAMUT[index]);
+        let high = at_row(&POINTER_GAMUT[index + 1]);
+        low + (high - low) * fraction
+    }
+}
+
+const POINTER_LIGHTNESS_START: f64 = 20.0;
+const POINTER_LIGHTNESS_STEP: f64 = 10.0;
+
+///The maximum chroma of Pointer's gamut, in `Lch` under illuminant C, for the
+///lightnesses 20 to 90 in steps of 10 and the hues 0° to 350° in steps of 10°.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const POINTER_GAMUT: [[f64; 36]; 8] = [
+    //L* = 20
+    [
+        30.0, 30.0, 34.0, 48.0, 40.0, 21.0, 15.0, 15.0, 15.0, 12.0, 16.0, 18.0,
+        14.0, 18.0, 20.0, 21.0, 24.0, 25.0, 25.0, 19.0, 19.0, 12.0, 12.0, 20.0,
+        16.0, 21.0, 24.0, 31.0, 29.0, 40.0, 55.0, 76.0, 71.0, 50.0, 49.0, 37.0,
+    ],
+    //L* = 30
+    [
+        56.0, 56.0, 61.0, 68.0, 66.0, 45.0, 37.0, 36.0, 32.0, 28.0, 30.0, 35.0,
+        32.0, 40.0, 42.0, 45.0, 48.0, 47.0, 48.0, 40.0, 37.0, 26.0, 28.0, 36.0,
+        34.0, 40.0, 41.0, 50.0, 55.0, 69.0, 81.0, 88.0, 84.0, 86.0, 73.0, 65.0,
+    ],
+    //L* = 40
+    [
+        77.0, 70.0, 74.0, 82.0, 90.0, 75.0, 59.0, 56.0, 48.0, 44.0, 45.0, 52.0,
+        49.0, 56.0, 60.0, 68.0, 68.0, 65.0, 64.0, 55.0, 45.0, 43.0, 40.0, 46.0,
+        47.0, 54.0, 51.0, 60.0, 61.0, 69.0, 72.0, 80.0, 86.0, 89.0, 87.0, 79.0,
+    ],
+    //L* = 50
+    [
+        77.0, 73.0, 76.0, 83.0, 93.0, 100.0, 82.0, 76.0, 64.0, 60.0, 58.0, 66.0,
+        64.0, 70.0, 76.0, 81.0, 82.0, 75.0, 71.0, 62.0, 46.0, 51.0, 48.0, 51.0,
+        50.0, 55.0, 56.0, 57.0, 57.0, 58.0, 57.0, 62.0, 74.0, 80.0, 83.0, 84.0,
+    ],
+    //L* = 60
+    [
+        65.0, 65.0, 68.0, 75.0, 82.0, 99.0, 103.0, 94.0, 82.0, 75.0, 72.0, 82.0,
+        78.0, 82.0, 87.0, 84.0, 83.0, 75.0, 69.0, 60.0, 43.0, 50.0, 49.0, 50.0,
+        47.0, 48.0, 46.0, 45.0, 46.0, 43.0, 42.0, 47.0, 57.0, 63.0, 71.0, 73.0,
+    ],
+    //L* = 70
+    [
+        50.0, 48.0, 51.0, 56.0, 60.0, 75.0, 98.0, 108.0, 105.0, 90.0, 86.0, 92.0,
+        90.0, 88.0, 90.0, 80.0, 69.0, 65.0, 50.0, 49.0, 35.0, 40.0, 38.0, 39.0,
+        36.0, 36.0, 33.0, 33.0, 34.0, 29.0, 30.0, 34.0, 40.0, 45.0, 51.0, 53.0,
+    ],
+    //L* = 80
+    [
+        30.0, 30.0, 30.0, 33.0, 35.0, 45.0, 66.0, 82.0, 115.0, 106.0, 100.0, 100.0,
+        95.0, 84.0, 71.0, 58.0, 49.0, 45.0, 41.0, 32.0, 22.0, 24.0, 23.0, 24.0,
+        21.0, 21.0, 20.0, 20.0, 18.0, 18.0, 17.0, 20.0, 24.0, 27.0, 28.0, 30.0,
+    ],
+    //L* = 90
+    [
+        8.0, 7.0, 9.0, 10.0, 10.0, 15.0, 23.0, 34.0, 48.0, 90.0, 108.0, 84.0,
+        50.0, 35.0, 30.0, 20.0, 15.0, 15.0, 16.0, 13.0, 7.0, 4.0, 6.0, 7.0,
+        4.0, 4.0, 6.0, 6.0, 4.0, 5.0, 5.0, 6.0, 8.0, 9.0, 4.0, 6.0,
+    ],
+];
+
+///The MacAdam limits of surface colors under an illuminant.
+///
+///The limits are the boundary of the object color solid, which contains the
+///colors of every reflectance from `0` to `1` under the illuminant. It's
+///computed from the CIE 1931 2° observer, sampled from 380 nm to 780 nm in
+///steps of 5 nm. Colors are given relative to the white point `Wp`, which is
+///scaled to the white of the illuminant before they are checked.
+#[derive(Clone, Debug)]
+pub struct MacAdamLimits<Wp> {
+    white: [f64; 3],
+    center: [f64; 3],
+    facets: Vec<([f64; 3], f64)>,
+    white_point: PhantomData<Wp>,
+}
+
+impl<Wp: WhitePoint> MacAdamLimits<Wp> {
+    ///Compute the limits for an illuminant with the relative spectral power
+    ///`spd`, sampled from 380 nm to 780 nm in steps of 5 nm.
+    ///
+    ///# Panics
+    ///
+    ///Panics if `spd` doesn't have 81 samples.
+    pub fn from_spd<T: Float>(spd: &[T]) -> MacAdamLimits<Wp> {
+        assert_eq!(
+            spd.len(),
+            CIE_1931_2.len(),
+            "the illuminant must be sampled from 380 nm to 780 nm in steps of 5 nm"
+        );
+
+        let mut generators: Vec<[f64; 3]> = spd.iter()
+            .zip(CIE_1931_2.iter())
+            .map(|(power, cmf)| {
+                let power = power.to_f64().unwrap();
+                [power * cmf[0], power * cmf[1], power * cmf[2]]
+            })
+            .collect();
+
+        let mut white = [0.0; 3];
+        for generator in &generators {
+            for (sum, value) in white.iter_mut().zip(generator.iter()) {
+                *sum += *value;
+            }
+        }
+
+        let scale = 1.0 / white[1];
+        for generator in &mut generators {
+            for value in generator.iter_mut() {
+                *value *= scale;
+            }
+        }
+        for value in white.iter_mut() {
+            *value *= scale;
+        }
+
+        MacAdamLimits {
+            white: white,
+            center: [white[0] / 2.0, white[1] / 2.0, white[2] / 2.0],
+            facets: facets(&generators),
+            white_point: PhantomData,
+        }
+    }
+
+    ///Compute the limits for an equal energy illuminant.
+    pub fn equal_energy() -> MacAdamLimits<Wp> {
+        MacAdamLimits::from_spd(&[1.0f64; 81])
+    }
+
+    ///Compute the limits for a black body radiator, such as CIE illuminant A
+    ///at 2856 K.
+    pub fn blackbody(temperature: f64) -> MacAdamLimits<Wp> {
+        MacAdamLimits::from_spd(&sample(|wavelength| {
+            cie_data::planck(temperature, wavelength)
+        }))
+    }
+
+    ///Compute the limits for CIE daylight with the correlated color
+    ///temperature `cct`, such as D65 at 6504 K.
+    pub fn daylight(cct: f64) -> MacAdamLimits<Wp> {
+        MacAdamLimits::from_spd(&sample(|wavelength| cie_data::daylight(cct, wavelength)))
+    }
+
+    ///Check if a color is within the limits.
+    ///
+    ///The distance is measured in chroma, so it's `0.0` or negative for
+    ///colors that are too light to be within the limits with any chroma.
+    pub fn check<C, T>(&self, color: C) -> SurfaceCheck<T>
+    where
+        C: IntoColor<Wp, T>,
+        T: Component + Float,
+    {
+        let xyz = color.into_xyz();
+        let lch = Lch::from(xyz);
+
+        SurfaceCheck {
+            is_inside: self.contains_xyz(xyz),
+            distance: self.max_chroma(lch.l, lch.hue) - lch.chroma,
+        }
+    }
+
+    ///The maximum chroma within the limits for the lightness `l` and hue
+    ///`hue`, in `Lch` relative to `Wp`.
+    pub fn max_chroma<T: Component + Float>(&self, l: T, hue: LabHue<T>) -> T {
+        let inside = |chroma: T| self.contains_xyz(Lch::<Wp, T>::with_wp(l, chroma, hue).into_xyz());
+
+        if !inside(T::zero()) {
+            return T::zero();
+        }
+
+        let (mut low, mut high) = (T::zero(), cast::<T, _>(MAX_SEARCH_CHROMA));
+        while high - low > cast(CHROMA_EPSILON) {
+            let middle = (low + high) / cast(2.0);
+            if inside(middle) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        low
+    }
+
+    ///Check if the `Xyz` values are within the object color solid.
+    fn contains_xyz<T: Component + Float>(&self, color: Xyz<Wp, T>) -> bool {
+        let white: Xyz<Wp, f64> = Wp::get_xyz();
+        let point = [
+            color.x.to_f64().unwrap() * self.white[0] / white.x - self.center[0],
+            color.y.to_f64().unwrap() * self.white[1] / white.y - self.center[1],
+            color.z.to_f64().unwrap() * self.white[2] / white.z - self.center[2],
+        ];
+
+        self.facets
+            .iter()
+            .all(|&(ref normal, half_width)| dot(normal, &point).abs() <= half_width + 1e-9)
+    }
+}
+
+///The upper limit of the chroma search.
+const MAX_SEARCH_CHROMA: f64 = 400.0;
+
+///The tolerance of the chroma search.
+const CHROMA_EPSILON: f64 = 0.0001;
+
+fn sample<F: Fn(f64) -> f64>(spd: F) -> Vec<f64> {
+    (0..CIE_1931_2.len())
+        .map(|i| spd(CMF_START + CMF_INTERVAL * i as f64))
+        .collect()
+}
+
+///The facet planes of the zonotope spanned by the generators, as unit
+///normals and the distances from the center to the planes.
+fn facets(generators: &[[f64; 3]]) -> Vec<([f64; 3], f64)> {
+    let mut facets = vec![];
+
+    for (i, a) in generators.iter().enumerate() {
+        for b in &generators[i + 1..] {
+            let normal = [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ];
+            let length = dot(&normal, &normal).sqrt();
+            if length < 1e-12 {
+                continue;
+            }
+
+            let normal = [normal[0] / length, normal[1] / length, normal[2] / length];
+            let half_width = generators
+                .iter()
+                .map(|generator| dot(&normal, generator).abs())
+                .sum::<f64>() / 2.0;
+            facets.push((normal, half_width));
+        }
+    }
+
+    facets
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[cfg(test)]
+mod test {
+    use {Lab, LabHue, Lch, Srgb, Xyz};
+    use white_point::{A, D65, E};
+    use super::{pointer_gamut, pointer_max_chroma, MacAdamLimits};
+
+    #[test]
+    fn pointer_samples() {
+        assert_relative_eq!(pointer_max_chroma(50.0f64, LabHue::from(50.0)), 100.0);
+        assert_relative_eq!(pointer_max_chroma(80.0f64, LabHue::from(80.0)), 115.0);
+        assert_relative_eq!(pointer_max_chroma(45.0f64, LabHue::from(5.0)), 74.25);
+        assert_relative_eq!(pointer_max_chroma(10.0f64, LabHue::from(0.0)), 15.0);
+        assert_relative_eq!(pointer_max_chroma(100.0f64, LabHue::from(0.0)), 0.0);
+    }
+
+    #[test]
+    fn pointer_check() {
+        let gray = Lch::new(50.0f64, 0.0, 0.0);
+        assert!(pointer_gamut(gray).is_inside);
+
+        let neon = Lch::new(50.0f64, 150.0, 300.0);
+        let check = pointer_gamut(neon);
+        assert!(!check.is_inside);
+        assert!(check.distance < -50.0);
+
+        //A fully saturated sRGB blue is outside of Pointer's gamut.
+        assert!(!pointer_gamut(Srgb::new(0.0f64, 0.0, 1.0).into_linear()).is_inside);
+    }
+
+    #[test]
+    fn macadam_white_and_black() {
+        let limits = MacAdamLimits::<E>::equal_energy();
+        let white: Xyz<E, f64> = Xyz::with_wp(1.0, 1.0, 1.0);
+
+        assert!(limits.check(white).is_inside);
+        assert!(limits.check(Xyz::<E, f64>::with_wp(0.0, 0.0, 0.0)).is_inside);
+        assert!(!limits.check(Xyz::<E, f64>::with_wp(1.01, 1.01, 1.01)).is_inside);
+        assert_relative_eq!(limits.max_chroma(100.0f64, LabHue::from(0.0)), 0.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn macadam_contains_pointer_gamut() {
+        let limits = MacAdamLimits::<D65>::daylight(6504.0);
+
+        for &l in &[30.0f64, 50.0, 70.0] {
+            for h in 0..12 {
+                let hue = LabHue::from(h as f64 * 30.0);
+                let lch: Lch<D65, f64> = Lch::with_wp(l, 0.0, hue);
+                let pointer = pointer_max_chroma(l, hue);
+                let macadam = limits.max_chroma(l, hue);
+
+                assert!(macadam > pointer, "{:?}: {} <= {}", lch, macadam, pointer);
+            }
+        }
+    }
+
+    #[test]
+    fn macadam_known_values() {
+        //The sRGB primaries are real surface colors, but only just for red.
+        let limits = MacAdamLimits::<D65>::daylight(6504.0);
+        let red = limits.check(Srgb::new(1.0f64, 0.0, 0.0).into_linear());
+        let green = limits.check(Srgb::new(0.0f64, 1.0, 0.0).into_linear());
+        assert!(red.is_inside);
+        assert!(red.distance > 0.0 && red.distance < 10.0);
+        assert!(green.is_inside);
+
+        //Lab colors at the edge of the solid.
+        let check = limits.check(Lab::new(50.0f64, 0.0, -150.0));
+        assert!(!check.is_inside);
+    }
+
+    #[test]
+    fn macadam_illuminant_a() {
+        let limits = MacAdamLimits::<A>::blackbody(2856.0);
+        let white: Xyz<A, f64> = Xyz::with_wp(1.098_5, 1.0, 0.355_8);
+
+        assert!(limits.check(white * 0.99).is_inside);
+        assert!(!limits.check(white * 1.01).is_inside);
+    }
+}
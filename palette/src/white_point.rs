@@ -5,6 +5,15 @@
 //! which are relatively orange compared to daylight. Defining "white" as daylight will give
 //! unacceptable results when attempting to color-correct a photograph taken with incandescent
 //! lighting.
+//!
+//! The white points are for the CIE 1931 2° Standard Observer, and the ones named `…Degree10`
+//! are for the CIE 1964 10° Standard Observer. Only A, C, D50, D55, D65, D75, D93, E and F1-F12
+//! have 10° variants. B and the LED series are only provided for the 2° observer, since their
+//! spectral power distributions aren't included in this library, and DCI and ACES are defined
+//! by chromaticity coordinates alone.
+//! Other white points can be computed from a spectral power distribution with
+//! [`Spd::white_point`](../spectral/struct.Spd.html#method.white_point) and used as a
+//! [`DynamicWhitePoint`](struct.DynamicWhitePoint.html).
 
 use num_traits::Float;
 
@@ -23,166 +32,512 @@ pub trait WhitePoint {
     fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T>;
 }
 
-/// CIE standard illuminant A
-///
-/// CIE standard illuminant A is intended to represent typical, domestic, tungsten-filament
-/// lighting. Its relative spectral power distribution is that of a Planckian radiator at a
-/// temperature of approximately 2856 K. Uses the CIE 1932 2° Standard Observer
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct A;
-impl WhitePoint for A {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(1.09850), T::one(), cast(0.35585))
-    }
+macro_rules! white_point {
+    ($(#[$attr:meta])* $name:ident: ($x:expr, $z:expr)) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub struct $name;
+        impl WhitePoint for $name {
+            fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
+                Xyz::with_wp(cast($x), T::one(), cast($z))
+            }
+        }
+    };
 }
-/// CIE standard illuminant B
-///
-/// CIE standard illuminant B represents noon sunlight, with a correlated color temperature (CCT)
-/// of 4874 K Uses the CIE 1932 2° Standard Observer
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct B;
-impl WhitePoint for B {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.99072), T::one(), cast(0.85223))
-    }
+
+//Defines each illuminant together with its 10° Standard Observer variant, if
+//there is one, to keep the two in one place.
+macro_rules! white_points {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident: ($x:expr, $z:expr)
+        $(, $(#[$attr10:meta])* $name10:ident: ($x10:expr, $z10:expr))*;
+    )*) => {
+        $(
+            white_point!($(#[$attr])* $name: ($x, $z));
+            $(white_point!($(#[$attr10])* $name10: ($x10, $z10));)*
+        )*
+    };
 }
-///CIE standard illuminant C
-///
-///CIE standard illuminant C represents the average day light with a CCT of 6774 K
-///Uses the CIE 1932 2° Standard Observer
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct C;
-impl WhitePoint for C {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.98074), T::one(), cast(1.18232))
-    }
+
+//The X and Z tristimulus values, with Y = 1. A, B, C and D50-D75 are from ASTM
+//E308, which tabulates both observers. D93 and F1-F12 are generated from the
+//spectral power distributions in `spectral::illuminant`, integrated with the
+//1931 and 1964 color matching functions, and the `generated_from_spectra` test
+//checks that both observers still match them. The LED series is computed from
+//the chromaticity coordinates published by the CIE, and DCI and ACES are
+//defined by their chromaticities alone. B and the LED series don't have 10°
+//variants, as documented in the module docs.
+white_points! {
+    /// CIE standard illuminant A
+    ///
+    /// CIE standard illuminant A is intended to represent typical, domestic, tungsten-filament
+    /// lighting. Its relative spectral power distribution is that of a Planckian radiator at a
+    /// temperature of approximately 2856 K. Uses the CIE 1932 2° Standard Observer
+    A: (1.09850, 0.35585),
+    /// CIE standard illuminant A
+    ///
+    /// CIE standard illuminant A for the 10° Standard Observer.
+    ADegree10: (1.11144, 0.35200);
+
+    /// CIE standard illuminant B
+    ///
+    /// CIE standard illuminant B represents noon sunlight, with a correlated color temperature (CCT)
+    /// of 4874 K Uses the CIE 1932 2° Standard Observer
+    B: (0.99072, 0.85223);
+
+    ///CIE standard illuminant C
+    ///
+    ///CIE standard illuminant C represents the average day light with a CCT of 6774 K
+    ///Uses the CIE 1932 2° Standard Observer
+    C: (0.98074, 1.18232),
+    ///CIE standard illuminant C
+    ///
+    ///CIE standard illuminant C for the 10° Standard Observer.
+    CDegree10: (0.97285, 1.16145);
+
+    ///CIE D series standard illuminant - D50
+    ///
+    ///D50 White Point is the natural daylight with a color temperature of around 5000K
+    ///for 2° Standard Observer.
+    D50: (0.96422, 0.82521),
+    ///CIE D series standard illuminant - D50
+    ///
+    ///D50 White Point is the natural daylight with a color temperature of around 5000K
+    ///for 10° Standard Observer.
+    D50Degree10: (0.9672, 0.8143);
+
+    ///CIE D series standard illuminant - D55
+    ///
+    ///D55 White Point is the natural daylight with a color temperature of around 5500K
+    ///for 2° Standard Observer.
+    D55: (0.95682, 0.92149),
+    ///CIE D series standard illuminant - D55
+    ///
+    ///D55 White Point is the natural daylight with a color temperature of around 5500K
+    ///for 10° Standard Observer.
+    D55Degree10: (0.958, 0.9093);
+
+    ///CIE D series standard illuminant - D65
+    ///
+    ///D65 White Point is the natural daylight with a color temperature of 6500K
+    ///for 2° Standard Observer.
+    D65: (0.95047, 1.08883),
+    ///CIE D series standard illuminant - D65
+    ///
+    ///D65 White Point is the natural daylight with a color temperature of 6500K
+    ///for 10° Standard Observer.
+    D65Degree10: (0.9481, 1.073);
+
+    ///CIE D series standard illuminant - D75
+    ///
+    ///D75 White Point is the natural daylight with a color temperature of around 7500K
+    ///for 2° Standard Observer.
+    D75: (0.94972, 1.22638),
+    ///CIE D series standard illuminant - D75
+    ///
+    ///D75 White Point is the natural daylight with a color temperature of around 7500K
+    ///for 10° Standard Observer.
+    D75Degree10: (0.94416, 1.2064);
+
+    ///CIE D series illuminant - D93
+    ///
+    ///D93 White Point is the natural daylight with a color temperature of around 9300K, as
+    ///used for some displays, for 2° Standard Observer.
+    D93: (0.95321, 1.41357),
+    ///CIE D series illuminant - D93
+    ///
+    ///D93 White Point is the natural daylight with a color temperature of around 9300K, as
+    ///used for some displays, for 10° Standard Observer.
+    D93Degree10: (0.94292, 1.38599);
+
+    ///CIE standard illuminant E
+    ///
+    ///CIE standard illuminant E represents the equal energy radiator
+    ///Uses the CIE 1932 2° Standard Observer
+    E: (1.0, 1.0),
+    ///CIE standard illuminant E
+    ///
+    ///CIE standard illuminant E for the 10° Standard Observer.
+    EDegree10: (1.0, 1.0);
+
+    ///CIE fluorescent illuminant series - F1
+    ///
+    ///F1 represents a standard halophosphate fluorescent lamp, with a CCT of 6430 K, for 2°
+    ///Standard Observer.
+    F1: (0.92868, 1.03775),
+    ///CIE fluorescent illuminant series - F1
+    ///
+    ///F1 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+    F1Degree10: (0.94816, 1.03256);
+
+    ///CIE fluorescent illuminant series - F2
+    ///
+    ///F2 represents a semi-broadband fluorescent lamp for 2° Standard Observer.
+    F2: (0.99186, 0.67394),
+    ///CIE fluorescent illuminant series - F2
+    ///
+    ///F2 represents a semi-broadband fluorescent lamp for 10° Standard Observer.
+    F2Degree10: (1.03280, 0.69030);
+
+    ///CIE fluorescent illuminant series - F3
+    ///
+    ///F3 represents a standard halophosphate fluorescent lamp, with a CCT of 3450 K, for 2°
+    ///Standard Observer.
+    F3: (1.03799, 0.49933),
+    ///CIE fluorescent illuminant series - F3
+    ///
+    ///F3 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+    F3Degree10: (1.09010, 0.52002);
+
+    ///CIE fluorescent illuminant series - F4
+    ///
+    ///F4 represents a standard halophosphate fluorescent lamp, with a CCT of 2940 K, for 2°
+    ///Standard Observer.
+    F4: (1.09202, 0.38882),
+    ///CIE fluorescent illuminant series - F4
+    ///
+    ///F4 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+    F4Degree10: (1.15013, 0.41000);
+
+    ///CIE fluorescent illuminant series - F5
+    ///
+    ///F5 represents a standard halophosphate fluorescent lamp, with a CCT of 6350 K, for 2°
+    ///Standard Observer.
+    F5: (0.90902, 0.98818),
+    ///CIE fluorescent illuminant series - F5
+    ///
+    ///F5 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+    F5Degree10: (0.93391, 0.98693);
+
+    ///CIE fluorescent illuminant series - F6
+    ///
+    ///F6 represents a standard halophosphate fluorescent lamp, with a CCT of 4150 K, for 2°
+    ///Standard Observer.
+    F6: (0.97342, 0.60261),
+    ///CIE fluorescent illuminant series - F6
+    ///
+    ///F6 represents a standard halophosphate fluorescent lamp for 10° Standard Observer.
+    F6Degree10: (1.02180, 0.62109);
+
+    ///CIE fluorescent illuminant series - F7
+    ///
+    ///F7 represents a broadband fluorescent lamp for 2° Standard Observer.
+    F7: (0.95042, 1.08749),
+    ///CIE fluorescent illuminant series - F7
+    ///
+    ///F7 represents a broadband fluorescent lamp for 10° Standard Observer.
+    F7Degree10: (0.95793, 1.07690);
+
+    ///CIE fluorescent illuminant series - F8
+    ///
+    ///F8 represents a broadband fluorescent lamp, with a CCT of 5000 K, for 2° Standard
+    ///Observer.
+    F8: (0.96427, 0.82421),
+    ///CIE fluorescent illuminant series - F8
+    ///
+    ///F8 represents a broadband fluorescent lamp for 10° Standard Observer.
+    F8Degree10: (0.97119, 0.81187);
+
+    ///CIE fluorescent illuminant series - F9
+    ///
+    ///F9 represents a broadband fluorescent lamp, with a CCT of 4150 K, for 2° Standard
+    ///Observer.
+    F9: (1.00380, 0.67944),
+    ///CIE fluorescent illuminant series - F9
+    ///
+    ///F9 represents a broadband fluorescent lamp for 10° Standard Observer.
+    F9Degree10: (1.02125, 0.67866);
+
+    ///CIE fluorescent illuminant series - F10
+    ///
+    ///F10 represents a narrowband fluorescent lamp, with a CCT of 5000 K, for 2° Standard
+    ///Observer.
+    F10: (0.96385, 0.82354),
+    ///CIE fluorescent illuminant series - F10
+    ///
+    ///F10 represents a narrowband fluorescent lamp for 10° Standard Observer.
+    F10Degree10: (0.98958, 0.83286);
+
+    ///CIE fluorescent illuminant series - F11
+    ///
+    ///F11 represents a narrowband fluorescent lamp for 2° Standard Observer.
+    F11: (1.00961, 0.64351),
+    ///CIE fluorescent illuminant series - F11
+    ///
+    ///F11 represents a narrowband fluorescent lamp for 10° Standard Observer.
+    F11Degree10: (1.03864, 0.65609);
+
+    ///CIE fluorescent illuminant series - F12
+    ///
+    ///F12 represents a narrowband fluorescent lamp, with a CCT of 3000 K, for 2° Standard
+    ///Observer.
+    F12: (1.08117, 0.39276),
+    ///CIE fluorescent illuminant series - F12
+    ///
+    ///F12 represents a narrowband fluorescent lamp for 10° Standard Observer.
+    F12Degree10: (1.11483, 0.40368);
+
+    ///CIE LED illuminant series - LED-B1
+    ///
+    ///LED-B1 represents a phosphor converted blue LED, with a CCT of 2733 K, for 2° Standard
+    ///Observer.
+    LedB1: (1.11820, 0.33399);
+
+    ///CIE LED illuminant series - LED-B2
+    ///
+    ///LED-B2 represents a phosphor converted blue LED, with a CCT of 2998 K, for 2° Standard
+    ///Observer.
+    LedB2: (1.08599, 0.40653);
+
+    ///CIE LED illuminant series - LED-B3
+    ///
+    ///LED-B3 represents a phosphor converted blue LED, with a CCT of 4103 K, for 2° Standard
+    ///Observer.
+    LedB3: (1.00886, 0.67714);
+
+    ///CIE LED illuminant series - LED-B4
+    ///
+    ///LED-B4 represents a phosphor converted blue LED, with a CCT of 5109 K, for 2° Standard
+    ///Observer.
+    LedB4: (0.97716, 0.87836);
+
+    ///CIE LED illuminant series - LED-B5
+    ///
+    ///LED-B5 represents a phosphor converted blue LED, with a CCT of 6598 K, for 2° Standard
+    ///Observer.
+    LedB5: (0.96354, 1.12670);
+
+    ///CIE LED illuminant series - LED-BH1
+    ///
+    ///LED-BH1 represents a hybrid of phosphor converted blue LEDs and red LEDs, with a CCT of
+    ///2851 K, for 2° Standard Observer.
+    LedBh1: (1.10034, 0.35908);
+
+    ///CIE LED illuminant series - LED-RGB1
+    ///
+    ///LED-RGB1 represents a mix of red, green and blue LEDs, with a CCT of 2840 K, for 2°
+    ///Standard Observer.
+    LedRgb1: (1.08217, 0.29257);
+
+    ///CIE LED illuminant series - LED-V1
+    ///
+    ///LED-V1 represents a phosphor converted violet LED, with a CCT of 2724 K, for 2° Standard
+    ///Observer.
+    LedV1: (1.12324, 0.34651);
+
+    ///CIE LED illuminant series - LED-V2
+    ///
+    ///LED-V2 represents a phosphor converted violet LED, with a CCT of 4070 K, for 2° Standard
+    ///Observer.
+    LedV2: (1.00159, 0.64742);
+
+    ///DCI-P3 white point
+    ///
+    ///The white point of the DCI-P3 digital cinema projector standard, at x = 0.314 and
+    ///y = 0.351.
+    Dci: (0.89459, 0.95442);
+
+    ///ACES white point
+    ///
+    ///The white point of the Academy Color Encoding System, close to D60, at x = 0.32168 and
+    ///y = 0.33767.
+    Aces: (0.95265, 1.00883);
 }
-///CIE D series standard illuminant - D50
+
+///The white point tag for colors that are relative to a
+///[`DynamicWhitePoint`](struct.DynamicWhitePoint.html).
 ///
-///D50 White Point is the natural daylight with a color temperature of around 5000K
-///for 2° Standard Observer.
+///It's returned by the methods that take a `DynamicWhitePoint`, such as
+///[`Lab::from_xyz_with_white`](../struct.Lab.html#method.from_xyz_with_white)
+///and [`Adapter::with_white_points`](../chromatic_adaptation/struct.Adapter.html#method.with_white_points),
+///since the actual white point isn't known until runtime. It doesn't have any
+///tristimulus values of its own, so conversions that need them, such as
+///`Lab<Dynamic>` to `Xyz`, panic instead of silently using the wrong white.
+///Use the methods that take a `DynamicWhitePoint` to convert these colors, or
+///[`DynamicWhitePoint::get_xyz`](struct.DynamicWhitePoint.html#method.get_xyz)
+///and `with_wp` to give them a static white point.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D50;
-impl WhitePoint for D50 {
+pub struct Dynamic;
+
+impl WhitePoint for Dynamic {
     fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.96422), T::one(), cast(0.82521))
+        panic!(
+            "the `Dynamic` white point has no static tristimulus values, use the methods that \
+             take a `DynamicWhitePoint` instead"
+        )
     }
 }
-///CIE D series standard illuminant - D55
+
+///A white point with tristimulus values that are only known at runtime, such
+///as a measured display white or an estimated scene illuminant.
 ///
-///D55 White Point is the natural daylight with a color temperature of around 5500K
-///for 2° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D55;
-impl WhitePoint for D55 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.95682), T::one(), cast(0.92149))
-    }
-}
-///CIE D series standard illuminant - D65
+///Colors that are converted with a `DynamicWhitePoint` are tagged with the
+///[`Dynamic`](struct.Dynamic.html) white point, so they can't be mixed up
+///with colors that have a static white point.
 ///
-///D65 White Point is the natural daylight with a color temperature of 6500K
-///for 2° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D65;
-impl WhitePoint for D65 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.95047), T::one(), cast(1.08883))
-    }
-}
-///CIE D series standard illuminant - D75
+///```
+///use palette::Xyz;
+///use palette::white_point::{DynamicWhitePoint, D65};
 ///
-///D75 White Point is the natural daylight with a color temperature of around 7500K
-///for 2° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D75;
-impl WhitePoint for D75 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.94972), T::one(), cast(1.22638))
-    }
-}
-///CIE standard illuminant E
+///let measured = DynamicWhitePoint::from_chromaticity(0.3127, 0.3290);
+///assert!(measured.is_close_to::<D65>(0.001));
 ///
-///CIE standard illuminant E represents the equal energy radiator
-///Uses the CIE 1932 2° Standard Observer
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct E;
-impl WhitePoint for E {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(T::one(), T::one(), T::one())
-    }
+///let d65: DynamicWhitePoint<f32> = D65.into();
+///let white: Xyz<D65, f32> = d65.get_xyz();
+///```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DynamicWhitePoint<T> {
+    ///The X tristimulus value, relative to Y.
+    pub x: T,
+    ///The Y tristimulus value, which is normally `1.0`.
+    pub y: T,
+    ///The Z tristimulus value, relative to Y.
+    pub z: T,
 }
-///CIE fluorescent illuminant series - F2
-///
-///F2 represents a semi-broadband fluorescent lamp for 2° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct F2;
-impl WhitePoint for F2 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.99186), T::one(), cast(0.67393))
+
+impl<T: Component + Float> DynamicWhitePoint<T> {
+    ///Create a white point from its tristimulus values. The values are used as
+    ///they are, so `y` should normally be `1.0`.
+    pub fn new(x: T, y: T, z: T) -> DynamicWhitePoint<T> {
+        DynamicWhitePoint { x: x, y: y, z: z }
     }
-}
-///CIE fluorescent illuminant series - F7
-///
-///F7 represents a broadband fluorescent lamp for 2° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct F7;
-impl WhitePoint for F7 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.95041), T::one(), cast(1.08747))
+
+    ///Create a white point from the tristimulus values of a white color, such
+    ///as a measurement. The values are scaled to make `y` equal to `1.0`.
+    pub fn from_xyz<Wp: WhitePoint>(color: Xyz<Wp, T>) -> DynamicWhitePoint<T> {
+        DynamicWhitePoint::new(color.x / color.y, T::one(), color.z / color.y)
     }
-}
-///CIE fluorescent illuminant series - F11
-///
-///F11 represents a narrowband fluorescent lamp for 2° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct F11;
-impl WhitePoint for F11 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(1.00962), T::one(), cast(0.64350))
+
+    ///Create a white point from its CIE 1931 chromaticity coordinates, with
+    ///`y` equal to `1.0`.
+    pub fn from_chromaticity(x: T, y: T) -> DynamicWhitePoint<T> {
+        DynamicWhitePoint::new(x / y, T::one(), (T::one() - x - y) / y)
     }
-}
-///CIE D series standard illuminant - D50
-///
-///D50 White Point is the natural daylight with a color temperature of around 5000K
-///for 10° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D50Degree10;
-impl WhitePoint for D50Degree10 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.9672), T::one(), cast(0.8143))
+
+    ///Create a white point with the same tristimulus values as the static
+    ///white point `Wp`.
+    pub fn from_static<Wp: WhitePoint>() -> DynamicWhitePoint<T> {
+        let xyz: Xyz<Wp, T> = Wp::get_xyz();
+        DynamicWhitePoint::new(xyz.x, xyz.y, xyz.z)
     }
-}
-///CIE D series standard illuminant - D55
-///
-///D55 White Point is the natural daylight with a color temperature of around 5500K
-///for 10° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D55Degree10;
-impl WhitePoint for D55Degree10 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.958), T::one(), cast(0.9093))
+
+    ///Get the Xyz tristimulus values of the white point.
+    pub fn get_xyz<Wp: WhitePoint>(&self) -> Xyz<Wp, T> {
+        Xyz::with_wp(self.x, self.y, self.z)
+    }
+
+    ///Check if the tristimulus values of this white point are within
+    ///`epsilon` of those of the static white point `Wp`.
+    pub fn is_close_to<Wp: WhitePoint>(&self, epsilon: T) -> bool {
+        let other = Self::from_static::<Wp>();
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
     }
 }
-///CIE D series standard illuminant - D65
-///
-///D65 White Point is the natural daylight with a color temperature of 6500K
-///for 10° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D65Degree10;
-impl WhitePoint for D65Degree10 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.9481), T::one(), cast(1.073))
+
+impl<Wp: WhitePoint, T: Component + Float> From<Wp> for DynamicWhitePoint<T> {
+    fn from(_: Wp) -> DynamicWhitePoint<T> {
+        DynamicWhitePoint::from_static::<Wp>()
     }
 }
-///CIE D series standard illuminant - D75
-///
-///D75 White Point is the natural daylight with a color temperature of around 7500K
-///for 10° Standard Observer.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct D75Degree10;
-impl WhitePoint for D75Degree10 {
-    fn get_xyz<Wp: WhitePoint, T: Component + Float>() -> Xyz<Wp, T> {
-        Xyz::with_wp(cast(0.94416), T::one(), cast(1.2064))
+
+#[cfg(test)]
+mod test {
+    use {Lab, Xyz};
+    use cct::{estimate, Method};
+    use spectral::{illuminant, Observer, Spd};
+    use spectral::illuminant::Fluorescent;
+    use super::{ADegree10, D50Degree10, D55Degree10, D65Degree10, D75Degree10, D93Degree10,
+                Dynamic, DynamicWhitePoint, EDegree10, F10Degree10, F11Degree10, F12Degree10,
+                F1Degree10, F2Degree10, F3Degree10, F4Degree10, F5Degree10, F6Degree10,
+                F7Degree10, F8Degree10, F9Degree10, WhitePoint, A, D50, D65, D93, F1, F10, F11,
+                F12, F2, F3, F4, F5, F6, F7, F8, F9, LedB1, LedB5, LedBh1, LedRgb1, LedV1,
+                LedV2};
+
+    #[test]
+    fn from_static() {
+        let a: DynamicWhitePoint<f64> = A.into();
+        assert_eq!(a, DynamicWhitePoint::new(1.09850, 1.0, 0.35585));
+        assert_eq!(a.get_xyz::<D50>(), A::get_xyz::<D50, f64>());
+        assert!(a.is_close_to::<A>(0.0));
+        assert!(!a.is_close_to::<D65>(0.01));
+    }
+
+    #[test]
+    fn correlated_color_temperatures() {
+        fn cct<Wp: WhitePoint>() -> f64 {
+            let white: Xyz<Wp, f64> = Wp::get_xyz();
+            estimate(white, Method::LocusSearch).unwrap().cct
+        }
+
+        assert_relative_eq!(cct::<F1>(), 6430.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<F4>(), 2940.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<F8>(), 5000.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<F12>(), 3000.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<LedB1>(), 2733.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<LedB5>(), 6598.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<LedBh1>(), 2851.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<LedRgb1>(), 2840.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<LedV1>(), 2724.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<LedV2>(), 4070.0, max_relative = 0.002);
+        assert_relative_eq!(cct::<D93>(), 9300.0, max_relative = 0.002);
+    }
+
+    #[test]
+    fn from_measurement() {
+        let measured = Xyz::<D65, f64>::with_wp(95.047, 100.0, 108.883);
+        let white = DynamicWhitePoint::from_xyz(measured);
+        assert!(white.is_close_to::<D65>(0.000001));
+
+        let white = DynamicWhitePoint::from_chromaticity(0.34567, 0.35850);
+        assert!(white.is_close_to::<D50>(0.0001));
+    }
+
+    #[test]
+    fn degree_10_from_spectra() {
+        fn check<Wp: WhitePoint>(spd: Spd<f64>) {
+            let white = spd.white_point(Observer::Cie1964);
+            assert!(white.is_close_to::<Wp>(0.002), "{:?}", white);
+        }
+
+        //The ASTM E308 values. There's no spectral power distribution for C.
+        check::<ADegree10>(illuminant::a());
+        check::<D50Degree10>(illuminant::d50());
+        check::<D55Degree10>(illuminant::d(5503.0));
+        check::<D65Degree10>(illuminant::d65());
+        check::<D75Degree10>(illuminant::d(7504.0));
+        check::<EDegree10>(illuminant::e());
+    }
+
+    #[test]
+    fn generated_from_spectra() {
+        fn check<Wp: WhitePoint, Wp10: WhitePoint>(spd: Spd<f64>) {
+            let white = spd.white_point(Observer::Cie1931);
+            assert!(white.is_close_to::<Wp>(0.00001), "{:?}", white);
+            let white = spd.white_point(Observer::Cie1964);
+            assert!(white.is_close_to::<Wp10>(0.00001), "{:?}", white);
+        }
+
+        check::<D93, D93Degree10>(illuminant::d(9300.0));
+        check::<F1, F1Degree10>(illuminant::fluorescent(Fluorescent::F1));
+        check::<F2, F2Degree10>(illuminant::fluorescent(Fluorescent::F2));
+        check::<F3, F3Degree10>(illuminant::fluorescent(Fluorescent::F3));
+        check::<F4, F4Degree10>(illuminant::fluorescent(Fluorescent::F4));
+        check::<F5, F5Degree10>(illuminant::fluorescent(Fluorescent::F5));
+        check::<F6, F6Degree10>(illuminant::fluorescent(Fluorescent::F6));
+        check::<F7, F7Degree10>(illuminant::fluorescent(Fluorescent::F7));
+        check::<F8, F8Degree10>(illuminant::fluorescent(Fluorescent::F8));
+        check::<F9, F9Degree10>(illuminant::fluorescent(Fluorescent::F9));
+        check::<F10, F10Degree10>(illuminant::fluorescent(Fluorescent::F10));
+        check::<F11, F11Degree10>(illuminant::fluorescent(Fluorescent::F11));
+        check::<F12, F12Degree10>(illuminant::fluorescent(Fluorescent::F12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn dynamic_has_no_values() {
+        let lab = Lab::from_xyz_with_white(Xyz::<D65, f64>::with_wp(0.3, 0.2, 0.1), &D65.into());
+        let _: Xyz<Dynamic, f64> = lab.into();
     }
 }
@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 
 use {Alpha, Lab, Luma, Yxy};
 use {Component, ComponentWise, Limited, Mix, Pixel, Shade};
-use white_point::{D65, WhitePoint};
+use white_point::{Dynamic, DynamicWhitePoint, D65, WhitePoint};
 use rgb::{Rgb, RgbSpace, RgbStandard};
 use luma::LumaStandard;
 use encoding::pixel::RawPixel;
@@ -106,6 +106,20 @@ where
     }
 }
 
+impl<T> Xyz<Dynamic, T>
+where
+    T: Component + Float,
+{
+    ///Convert from CIE L\*a\*b\*, relative to a white point that is only
+    ///known at runtime.
+    pub fn from_lab_with_white(
+        color: Lab<Dynamic, T>,
+        white: &DynamicWhitePoint<T>,
+    ) -> Xyz<Dynamic, T> {
+        relative_from_lab(color) * white.get_xyz()
+    }
+}
+
 ///<span id="Xyza"></span>[`Xyza`](type.Xyza.html) implementations.
 impl<T> Alpha<Xyz<D65, T>, T>
 where
@@ -173,24 +187,32 @@ where
     Wp: WhitePoint,
 {
     fn from(color: Lab<Wp, T>) -> Self {
-        let y = (color.l + cast(16.0)) / cast(116.0);
-        let x = y + (color.a / cast(500.0));
-        let z = y - (color.b / cast(200.0));
-
-        fn convert<T: Component + Float>(c: T) -> T {
-            let epsilon: T = cast(6.0 / 29.0);
-            let kappa: T = cast(108.0 / 841.0);
-            let delta: T = cast(4.0 / 29.0);
-
-            if c > epsilon {
-                c.powi(3)
-            } else {
-                (c - delta) * kappa
-            }
-        }
+        relative_from_lab(color) * Wp::get_xyz()
+    }
+}
 
-        Xyz::with_wp(convert(x), convert(y), convert(z)) * Wp::get_xyz()
+fn relative_from_lab<Wp, T>(color: Lab<Wp, T>) -> Xyz<Wp, T>
+where
+    T: Component + Float,
+    Wp: WhitePoint,
+{
+    let y = (color.l + cast(16.0)) / cast(116.0);
+    let x = y + (color.a / cast(500.0));
+    let z = y - (color.b / cast(200.0));
+
+    fn convert<T: Component + Float>(c: T) -> T {
+        let epsilon: T = cast(6.0 / 29.0);
+        let kappa: T = cast(108.0 / 841.0);
+        let delta: T = cast(4.0 / 29.0);
+
+        if c > epsilon {
+            c.powi(3)
+        } else {
+            (c - delta) * kappa
+        }
     }
+
+    Xyz::with_wp(convert(x), convert(y), convert(z))
 }
 
 impl<Wp, T, S> From<Luma<S, T>> for Xyz<Wp, T>
@@ -6,9 +6,10 @@ use std::marker::PhantomData;
 use {Alpha, Luma, Xyz};
 use luma::LumaStandard;
 use {Component, ComponentWise, IntoColor, Limited, Mix, Pixel, Shade};
-use white_point::{D65, WhitePoint};
+use white_point::{DynamicWhitePoint, D65, WhitePoint};
 use encoding::pixel::RawPixel;
-use clamp;
+use spectral::{DominantWavelength, Observer, Wavelength};
+use {cast, clamp};
 
 /// CIE 1931 Yxy (xyY) with an alpha component. See the [`Yxya` implementation
 /// in `Alpha`](struct.Alpha.html#Yxya).
@@ -104,6 +105,150 @@ where
     }
 }
 
+impl<Wp, T> Yxy<Wp, T>
+where
+    T: Component + Float,
+    Wp: WhitePoint,
+{
+    ///Find the dominant or complementary wavelength and the purity of the
+    ///color, relative to the white point `Wp`, with the spectral locus of
+    ///`observer`. Returns `None` for the white point itself.
+    ///
+    ///```
+    ///use palette::Yxy;
+    ///use palette::spectral::{Observer, Wavelength};
+    ///
+    ///let orange = Yxy::new(0.54369557f64, 0.32107944, 0.2);
+    ///let dominant = orange.dominant_wavelength(Observer::Cie1931).unwrap();
+    ///
+    ///if let Wavelength::Dominant(wavelength) = dominant.wavelength {
+    ///    assert!((wavelength - 616.0).abs() < 1.0);
+    ///}
+    ///```
+    pub fn dominant_wavelength(&self, observer: Observer) -> Option<DominantWavelength<T>> {
+        self.dominant_wavelength_with_white(&DynamicWhitePoint::from_static::<Wp>(), observer)
+    }
+
+    ///Find the dominant or complementary wavelength and the purity of the
+    ///color, relative to a white point that is only known at runtime instead
+    ///of `Wp`. Returns `None` for the white point itself.
+    pub fn dominant_wavelength_with_white(
+        &self,
+        white: &DynamicWhitePoint<T>,
+        observer: Observer,
+    ) -> Option<DominantWavelength<T>> {
+        let sum = white.x + white.y + white.z;
+        let white = (white.x / sum, white.y / sum);
+        let direction = (self.x - white.0, self.y - white.1);
+        if direction.0 == T::zero() && direction.1 == T::zero() {
+            return None;
+        }
+
+        let locus = spectral_locus(observer);
+        let (wavelength, boundary) = match intersect_locus(&locus, white, direction) {
+            Some((wavelength, boundary)) => (Wavelength::Dominant(wavelength), boundary),
+            None => {
+                let opposite = (-direction.0, -direction.1);
+                let (wavelength, _) = intersect_locus(&locus, white, opposite)?;
+                let purple_line = [locus[0], locus[locus.len() - 1]];
+                let (_, boundary) = intersect(&purple_line, white, direction)?;
+                (Wavelength::Complementary(wavelength), boundary)
+            }
+        };
+
+        let distance = |point: (T, T)| (point.0 - white.0).hypot(point.1 - white.1);
+        let excitation_purity = direction.0.hypot(direction.1) / distance(boundary);
+
+        Some(DominantWavelength {
+            wavelength: wavelength,
+            excitation_purity: excitation_purity,
+            colorimetric_purity: excitation_purity * boundary.1 / self.y,
+        })
+    }
+}
+
+///The chromaticity coordinates of the spectral locus, for every tabulated
+///wavelength of `observer` from 380 nm. The wavelengths in between are
+///interpolated linearly in the chromaticity diagram.
+///
+///The locus ends where `x` stops increasing, at about 700 nm. The tabulated
+///values are so small past that point that their chromaticities are mostly
+///rounding noise, so the purple line starts there instead.
+fn spectral_locus<T: Float>(observer: Observer) -> Vec<(T, T)> {
+    let mut locus: Vec<(T, T)> = (0..81)
+        .map(|i| {
+            let [x, y, z] = observer.cmf::<T>(cast(380.0 + 5.0 * i as f64));
+            let sum = x + y + z;
+            (x / sum, y / sum)
+        })
+        .collect();
+
+    let bluest = (1..locus.len())
+        .find(|&i| locus[i].0 > locus[i - 1].0)
+        .unwrap_or(1);
+    let end = (bluest..locus.len())
+        .find(|&i| locus[i].0 <= locus[i - 1].0)
+        .unwrap_or(locus.len());
+    locus.truncate(end);
+    locus
+}
+
+///Find where the ray from `origin` in `direction` meets the spectral locus and
+///return the wavelength and the intersection.
+fn intersect_locus<T: Float>(
+    locus: &[(T, T)],
+    origin: (T, T),
+    direction: (T, T),
+) -> Option<(T, (T, T))> {
+    intersect(locus, origin, direction).map(|(segment, point)| {
+        (cast::<T, _>(380.0) + segment * cast(5.0), point)
+    })
+}
+
+///Find the nearest point where the ray from `origin` in `direction` meets the
+///line segments between the points in `path`. Returns the position along the
+///path, where `1.0` is the second point, and the intersection.
+fn intersect<T: Float>(path: &[(T, T)], origin: (T, T), direction: (T, T)) -> Option<(T, (T, T))> {
+    let cross = |a: (T, T), b: (T, T)| a.0 * b.1 - a.1 * b.0;
+    //Rays through the end points of the path shouldn't slip past them
+    //because of rounding errors.
+    let tolerance = T::epsilon().sqrt();
+    let mut nearest: Option<(T, T)> = None;
+
+    for (i, segment) in path.windows(2).enumerate() {
+        let edge = (segment[1].0 - segment[0].0, segment[1].1 - segment[0].1);
+        let denominator = cross(direction, edge);
+        if denominator == T::zero() {
+            continue;
+        }
+
+        let offset = (segment[0].0 - origin.0, segment[0].1 - origin.1);
+        let distance = cross(offset, edge) / denominator;
+        let position = cross(offset, direction) / denominator;
+
+        let is_nearer = match nearest {
+            Some((nearest, _)) => distance < nearest,
+            None => true,
+        };
+        if distance > T::zero() && position >= -tolerance && position <= T::one() + tolerance
+            && is_nearer
+        {
+            let position = position.max(T::zero()).min(T::one());
+            nearest = Some((distance, cast::<T, _>(i as f64) + position));
+        }
+    }
+
+    nearest.map(|(distance, position)| {
+        (
+            position,
+            (
+                origin.0 + direction.0 * distance,
+                origin.1 + direction.1 * distance,
+            ),
+        )
+    })
+}
+
 ///<span id="Yxya"></span>[`Yxya`](type.Yxya.html) implementations.
 impl<T> Alpha<Yxy<D65, T>, T>
 where
@@ -432,9 +577,9 @@ where
 #[cfg(test)]
 mod test {
     use super::Yxy;
-    use LinSrgb;
-    use LinLuma;
-    use white_point::D65;
+    use {LinLuma, LinSrgb, Mix};
+    use spectral::{Observer, Wavelength};
+    use white_point::{WhitePoint, D65};
 
     #[test]
     fn luma() {
@@ -464,6 +609,85 @@ mod test {
         assert_relative_eq!(a, b, epsilon = 0.000001);
     }
 
+    #[test]
+    fn dominant_wavelength() {
+        let orange = Yxy::new(0.54369557, 0.32107944, 0.2);
+        let dominant = orange.dominant_wavelength(Observer::Cie1931).unwrap();
+        match dominant.wavelength {
+            Wavelength::Dominant(wavelength) => {
+                assert_relative_eq!(wavelength, 616.0, epsilon = 1.0)
+            }
+            wavelength => panic!("unexpected {:?}", wavelength),
+        }
+        assert_relative_eq!(dominant.excitation_purity, 0.6229, epsilon = 0.005);
+    }
+
+    #[test]
+    fn spectral_colors() {
+        let [x, y, z] = Observer::Cie1931.cmf(520.0);
+        let sum = x + y + z;
+        let green = Yxy::new(x / sum, y / sum, 0.5);
+        let dominant = green.dominant_wavelength(Observer::Cie1931).unwrap();
+
+        match dominant.wavelength {
+            Wavelength::Dominant(wavelength) => {
+                assert_relative_eq!(wavelength, 520.0, epsilon = 0.000001)
+            }
+            wavelength => panic!("unexpected {:?}", wavelength),
+        }
+        assert_relative_eq!(dominant.excitation_purity, 1.0, epsilon = 0.000001);
+        assert_relative_eq!(dominant.colorimetric_purity, 1.0, epsilon = 0.000001);
+
+        let white: Yxy<D65, f64> = Yxy::from(D65::get_xyz::<D65, f64>());
+        let pastel = white.mix(&green, 0.5);
+        let dominant = pastel.dominant_wavelength(Observer::Cie1931).unwrap();
+        match dominant.wavelength {
+            Wavelength::Dominant(wavelength) => {
+                assert_relative_eq!(wavelength, 520.0, epsilon = 0.000001)
+            }
+            wavelength => panic!("unexpected {:?}", wavelength),
+        }
+        assert_relative_eq!(dominant.excitation_purity, 0.5, epsilon = 0.000001);
+        assert!(dominant.colorimetric_purity > 0.5);
+    }
+
+    #[test]
+    fn deep_red() {
+        let white: Yxy<D65, f64> = Yxy::from(D65::get_xyz::<D65, f64>());
+
+        for &observer in &[Observer::Cie1931, Observer::Cie1964] {
+            let [x, y, z] = observer.cmf(700.0);
+            let sum = x + y + z;
+            let red = white.mix(&Yxy::new(x / sum, y / sum, 0.1), 0.8);
+            let dominant = red.dominant_wavelength(observer).unwrap();
+
+            match dominant.wavelength {
+                Wavelength::Dominant(wavelength) => {
+                    assert_relative_eq!(wavelength, 700.0, epsilon = 0.5)
+                }
+                wavelength => panic!("unexpected {:?}", wavelength),
+            }
+            assert_relative_eq!(dominant.excitation_purity, 0.8, epsilon = 0.01);
+        }
+    }
+
+    #[test]
+    fn complementary_wavelength() {
+        let white: Yxy<D65, f64> = Yxy::from(D65::get_xyz::<D65, f64>());
+        let purple = Yxy::new(0.35, 0.2, 0.1);
+        let dominant = purple.dominant_wavelength(Observer::Cie1931).unwrap();
+
+        match dominant.wavelength {
+            Wavelength::Complementary(wavelength) => {
+                assert!(wavelength > 495.0 && wavelength < 570.0, "{}", wavelength)
+            }
+            wavelength => panic!("unexpected {:?}", wavelength),
+        }
+        assert!(dominant.excitation_purity > 0.0 && dominant.excitation_purity < 1.0);
+
+        assert_eq!(white.dominant_wavelength(Observer::Cie1931), None);
+    }
+
     #[test]
     fn ranges() {
         assert_ranges!{